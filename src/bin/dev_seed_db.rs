@@ -28,11 +28,111 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         CREATE VIRTUAL TABLE genres_fts USING fts5(id UNINDEXED, name);
         CREATE VIRTUAL TABLE categories_fts USING fts5(id UNINDEXED, name);
 
+        CREATE TABLE apps(
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            last_modified INTEGER
+        );
+        CREATE INDEX apps_name_lower ON apps(LOWER(name));
+        CREATE VIRTUAL TABLE apps_fts USING fts5(id UNINDEXED, name);
+
+        CREATE TABLE sync_state(
+            name TEXT PRIMARY KEY,
+            value INTEGER NOT NULL
+        );
+
         CREATE TABLE app_cache(
-            appid INTEGER PRIMARY KEY,
+            appid INTEGER NOT NULL,
+            cc TEXT NOT NULL,
+            lang TEXT NOT NULL,
+            payload_json TEXT NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(appid, cc, lang)
+        );
+
+        CREATE TABLE package_cache(
+            subid INTEGER NOT NULL,
+            cc TEXT NOT NULL,
+            lang TEXT NOT NULL,
+            payload_json TEXT NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(subid, cc, lang)
+        );
+
+        CREATE TABLE search_cache(
+            query_key TEXT PRIMARY KEY,
             payload_json TEXT NOT NULL,
             fetched_at INTEGER NOT NULL
         );
+
+        CREATE TABLE app_news(
+            appid INTEGER NOT NULL,
+            gid TEXT NOT NULL,
+            title TEXT NOT NULL,
+            url TEXT,
+            author TEXT,
+            date INTEGER NOT NULL,
+            feedlabel TEXT,
+            feedname TEXT,
+            contents TEXT NOT NULL,
+            PRIMARY KEY(appid, gid)
+        );
+
+        CREATE TABLE app_news_fetch(
+            appid INTEGER NOT NULL,
+            query_key TEXT NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(appid, query_key)
+        );
+
+        CREATE TABLE app_tags(
+            appid INTEGER NOT NULL,
+            lang TEXT NOT NULL,
+            tagid INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            votes INTEGER NOT NULL,
+            position INTEGER NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(appid, lang, tagid)
+        );
+
+        CREATE TABLE app_tags_fetch(
+            appid INTEGER NOT NULL,
+            lang TEXT NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(appid, lang)
+        );
+
+        CREATE TABLE app_franchise(
+            appid INTEGER NOT NULL,
+            lang TEXT NOT NULL,
+            name TEXT,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(appid, lang)
+        );
+
+        CREATE TABLE app_languages(
+            appid INTEGER NOT NULL,
+            lang TEXT NOT NULL,
+            payload_json TEXT NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(appid, lang)
+        );
+
+        CREATE TABLE player_samples(
+            appid INTEGER NOT NULL,
+            sampled_at INTEGER NOT NULL,
+            player_count INTEGER NOT NULL,
+            PRIMARY KEY(appid, sampled_at)
+        );
+
+        CREATE TABLE review_summary_cache(
+            appid INTEGER NOT NULL,
+            query_key TEXT NOT NULL,
+            payload_json TEXT NOT NULL,
+            fetched_at INTEGER NOT NULL,
+            PRIMARY KEY(appid, query_key)
+        );
         ",
     )?;
 
@@ -1,12 +1,73 @@
 use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
 use skillinstaller::InstallSkillArgs;
 
+use crate::models::SearchItemKind;
+use crate::steam::{PurchaseType, ReviewFilter, ReviewType, SearchOs, SearchSort};
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum OutputFormatArg {
     Human,
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchOsArg {
+    Win,
+    Mac,
+    Linux,
+}
+
+impl From<SearchOsArg> for SearchOs {
+    fn from(value: SearchOsArg) -> Self {
+        match value {
+            SearchOsArg::Win => Self::Win,
+            SearchOsArg::Mac => Self::Mac,
+            SearchOsArg::Linux => Self::Linux,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum SearchSortArg {
+    #[default]
+    Relevance,
+    ReleaseDate,
+    Name,
+    Price,
+    PriceDesc,
+    ReviewScore,
+}
+
+impl From<SearchSortArg> for SearchSort {
+    fn from(value: SearchSortArg) -> Self {
+        match value {
+            SearchSortArg::Relevance => Self::Relevance,
+            SearchSortArg::ReleaseDate => Self::ReleaseDate,
+            SearchSortArg::Name => Self::Name,
+            SearchSortArg::Price => Self::Price,
+            SearchSortArg::PriceDesc => Self::PriceDesc,
+            SearchSortArg::ReviewScore => Self::ReviewScore,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchTypeArg {
+    App,
+    Package,
+    Bundle,
+}
+
+impl From<SearchTypeArg> for SearchItemKind {
+    fn from(value: SearchTypeArg) -> Self {
+        match value {
+            SearchTypeArg::App => Self::App,
+            SearchTypeArg::Package => Self::Package,
+            SearchTypeArg::Bundle => Self::Bundle,
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 #[command(
     name = "steam-cli",
@@ -18,6 +79,12 @@ pub struct Cli {
     pub format: OutputFormatArg,
     #[arg(long, global = true, action = ArgAction::SetTrue)]
     pub json: bool,
+    /// Store country code (e.g. us, de, br) used for prices and availability.
+    #[arg(long, global = true, env = "STEAM_CC")]
+    pub cc: Option<String>,
+    /// Store language (e.g. english, german, japanese).
+    #[arg(long, global = true, env = "STEAM_LANG", default_value = "english")]
+    pub lang: String,
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -27,8 +94,15 @@ pub enum Commands {
     Tags(DictCommand),
     Genres(DictCommand),
     Categories(DictCommand),
-    Search(SearchArgs),
-    App(AppArgs),
+    Search(Box<SearchArgs>),
+    Suggest(SuggestArgs),
+    App(AppCommand),
+    /// Package (sub) details: included apps, price and platforms.
+    Package(PackageArgs),
+    /// Local copy of the full app list for `--name` lookups.
+    Catalog(CatalogCommand),
+    /// Player-count history from samples recorded by `app players --record`.
+    Players(PlayersCommand),
     User(UserCommand),
     InstallSkill(InstallSkillArgs),
 }
@@ -60,23 +134,358 @@ pub struct DictCommand {
     pub action: DictSubcommands,
 }
 
+#[derive(Debug, Args)]
+pub struct CatalogCommand {
+    #[command(subcommand)]
+    pub action: CatalogSubcommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CatalogSubcommands {
+    /// Download new and changed apps from IStoreService/GetAppList (needs STEAM_API_KEY).
+    Sync(CatalogSyncArgs),
+    List(DictPagingArgs),
+    Find(FindArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CatalogSyncArgs {
+    /// Download the whole list again and replace the catalog, dropping apps Steam no longer lists.
+    #[arg(long, default_value_t = false)]
+    pub full: bool,
+    #[arg(long, default_value_t = false)]
+    pub include_dlc: bool,
+    #[arg(long, default_value_t = false)]
+    pub include_software: bool,
+}
+
+/// An appid, or an app name resolved against the local catalog.
+#[derive(Debug, Args)]
+pub struct AppTarget {
+    #[arg(required_unless_present = "name", conflicts_with = "name")]
+    pub appid: Option<i64>,
+    /// App name looked up in the catalog filled by `catalog sync`.
+    #[arg(long)]
+    pub name: Option<String>,
+}
+
 #[derive(Debug, Args)]
 pub struct SearchArgs {
+    #[arg(long, required_unless_present_any = ["any_tags", "term", "developer", "publisher", "categories", "genres"])]
+    pub tags: Option<String>,
+    /// Tags (ids or names) that results must not have.
     #[arg(long)]
-    pub tags: String,
+    pub exclude_tags: Option<String>,
+    /// A tag group (ids or names, ANDed); repeat to OR several groups together.
+    #[arg(long)]
+    pub any_tags: Vec<String>,
     #[arg(long)]
     pub term: Option<String>,
+    /// Exact developer name as shown on the Store, e.g. "Supergiant Games".
+    #[arg(long)]
+    pub developer: Option<String>,
+    /// Exact publisher name as shown on the Store.
+    #[arg(long)]
+    pub publisher: Option<String>,
+    /// Store categories (features) by id or name, e.g. "Online Co-op".
+    #[arg(long)]
+    pub categories: Option<String>,
+    /// Genres by id or name; sent to the Store as the matching tags.
+    #[arg(long)]
+    pub genres: Option<String>,
     #[arg(long, default_value_t = 20)]
     pub limit: usize,
     #[arg(long, default_value_t = 0)]
     pub offset: usize,
+    /// Fetch successive Store pages until N unique results are collected.
+    #[arg(long, conflicts_with = "limit")]
+    pub max_results: Option<usize>,
+    /// Fetch every page, up to a safety cap of 1000 results.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["limit", "max_results"])]
+    pub all: bool,
     #[arg(long, default_value_t = false)]
     pub with_facets: bool,
+    /// Maximum price in whole currency units, or "free".
+    #[arg(long)]
+    pub max_price: Option<String>,
+    /// Only return discounted items.
+    #[arg(long, default_value_t = false)]
+    pub specials: bool,
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub os: Vec<SearchOsArg>,
+    #[arg(long, default_value_t = false)]
+    pub hide_free_to_play: bool,
+    /// Only return Early Access titles.
+    #[arg(long, default_value_t = false)]
+    pub early_access: bool,
+    /// Only return unreleased (coming soon) titles.
+    #[arg(long, default_value_t = false)]
+    pub coming_soon: bool,
+    #[arg(long, value_enum, default_value_t = SearchSortArg::Relevance)]
+    pub sort: SearchSortArg,
+    /// Result kinds to return (default: all).
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub types: Vec<SearchTypeArg>,
+    /// Only return items supporting one of these Store language codes (e.g. japanese,schinese).
+    #[arg(long, value_delimiter = ',')]
+    pub language: Vec<String>,
+    #[arg(long, default_value_t = 3_600)]
+    pub ttl_sec: i64,
+}
+
+#[derive(Debug, Args)]
+pub struct SuggestArgs {
+    pub text: String,
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+}
+
+/// `app <appid...>` for details, or `app <subcommand>` for per-app data.
+#[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct AppCommand {
+    #[command(subcommand)]
+    pub action: Option<AppSubcommands>,
+    #[command(flatten)]
+    pub details: AppArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AppSubcommands {
+    /// Review summary and paged user reviews.
+    Reviews(AppReviewsArgs),
+    /// News and patch notes from ISteamNews.
+    News(AppNewsArgs),
+    /// Current player counts, or `players history` from recorded samples.
+    Players(AppPlayersCommand),
+    /// Achievement schema with global unlock percentages, rarest first.
+    Achievements(AppAchievementsArgs),
+    /// Community tags (id, name, votes) from the store page.
+    Tags(AppTagsArgs),
+    /// The app's DLC with names and prices.
+    Dlc(AppDlcArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct AppDlcArgs {
+    #[command(flatten)]
+    pub target: AppTarget,
+    #[arg(long, default_value_t = 50)]
+    pub limit: usize,
+    #[arg(long, default_value_t = 0)]
+    pub offset: usize,
+    /// TTL for the base app and every DLC in `app_cache`.
+    #[arg(long, default_value_t = 86_400)]
+    pub ttl_sec: i64,
+}
+
+#[derive(Debug, Args)]
+pub struct AppTagsArgs {
+    #[command(flatten)]
+    pub target: AppTarget,
+    #[arg(long, default_value_t = 86_400)]
+    pub ttl_sec: i64,
+}
+
+#[derive(Debug, Args)]
+pub struct AppAchievementsArgs {
+    #[command(flatten)]
+    pub target: AppTarget,
+    #[arg(long, default_value_t = 100)]
+    pub limit: usize,
+    #[arg(long, default_value_t = 0)]
+    pub offset: usize,
+}
+
+#[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct AppPlayersCommand {
+    #[command(subcommand)]
+    pub action: Option<PlayersSubcommands>,
+    #[command(flatten)]
+    pub current: AppPlayersArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct PlayersCommand {
+    #[command(subcommand)]
+    pub action: PlayersSubcommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PlayersSubcommands {
+    /// Min/max/average over locally recorded samples.
+    History(PlayersHistoryArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct AppPlayersArgs {
+    #[arg(required_unless_present = "name")]
+    pub appids: Vec<i64>,
+    /// App name looked up in the local catalog; repeat for several apps.
+    #[arg(long)]
+    pub name: Vec<String>,
+    /// Store each count as a timestamped sample for `players history`.
+    #[arg(long, default_value_t = false)]
+    pub record: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct PlayersHistoryArgs {
+    #[arg(required_unless_present = "name")]
+    pub appids: Vec<i64>,
+    /// App name looked up in the local catalog; repeat for several apps.
+    #[arg(long)]
+    pub name: Vec<String>,
+    /// Only use samples from the last N seconds.
+    #[arg(long, default_value_t = 604_800)]
+    pub window_sec: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ReviewFilterArg {
+    /// Sorted by helpfulness; the only order that honors --day-range.
+    #[default]
+    All,
+    Recent,
+    Updated,
+}
+
+impl From<ReviewFilterArg> for ReviewFilter {
+    fn from(value: ReviewFilterArg) -> Self {
+        match value {
+            ReviewFilterArg::All => Self::All,
+            ReviewFilterArg::Recent => Self::Recent,
+            ReviewFilterArg::Updated => Self::Updated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ReviewTypeArg {
+    #[default]
+    All,
+    Positive,
+    Negative,
+}
+
+impl From<ReviewTypeArg> for ReviewType {
+    fn from(value: ReviewTypeArg) -> Self {
+        match value {
+            ReviewTypeArg::All => Self::All,
+            ReviewTypeArg::Positive => Self::Positive,
+            ReviewTypeArg::Negative => Self::Negative,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum PurchaseTypeArg {
+    #[default]
+    All,
+    Steam,
+    NonSteam,
+}
+
+impl From<PurchaseTypeArg> for PurchaseType {
+    fn from(value: PurchaseTypeArg) -> Self {
+        match value {
+            PurchaseTypeArg::All => Self::All,
+            PurchaseTypeArg::Steam => Self::Steam,
+            PurchaseTypeArg::NonSteam => Self::NonSteam,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct AppReviewsArgs {
+    #[command(flatten)]
+    pub target: AppTarget,
+    /// Review language as a Store language code, or "all".
+    #[arg(long, default_value = "all")]
+    pub language: String,
+    #[arg(long, value_enum, default_value_t = PurchaseTypeArg::All)]
+    pub purchase_type: PurchaseTypeArg,
+    #[arg(long, value_enum, default_value_t = ReviewTypeArg::All)]
+    pub review_type: ReviewTypeArg,
+    #[arg(long, value_enum, default_value_t = ReviewFilterArg::All)]
+    pub filter: ReviewFilterArg,
+    /// Only count reviews from the last N days (requires --filter all; Store max 365).
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=365))]
+    pub day_range: Option<u32>,
+    /// Reviews per page (max 100); 0 returns only the summary.
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+    /// Cursor from a previous page's `next_cursor`.
+    #[arg(long)]
+    pub cursor: Option<String>,
+    /// TTL for the cached summary when --limit is 0.
+    #[arg(long, default_value_t = 3_600)]
+    pub ttl_sec: i64,
+}
+
+#[derive(Debug, Args)]
+pub struct AppNewsArgs {
+    #[command(flatten)]
+    pub target: AppTarget,
+    #[arg(long, default_value_t = 20)]
+    pub count: usize,
+    /// Truncate contents to N characters; 0 keeps the full text.
+    #[arg(long, default_value_t = 0)]
+    pub maxlength: usize,
+    /// Only items published at or after this time (unix seconds or YYYY-MM-DD).
+    #[arg(long)]
+    pub since: Option<String>,
+    /// Feed names to keep (e.g. steam_community_announcements,steam_updates).
+    #[arg(long, value_delimiter = ',')]
+    pub feeds: Vec<String>,
+    /// Read only from the local news cache; never touch the network.
+    #[arg(long, default_value_t = false)]
+    pub offline: bool,
+    #[arg(long, default_value_t = 1_800)]
+    pub ttl_sec: i64,
 }
 
 #[derive(Debug, Args)]
 pub struct AppArgs {
-    pub appid: i64,
+    /// One or more appids; more than one returns a batch envelope.
+    #[arg(required_unless_present_any = ["stdin", "stdin_names", "name"])]
+    pub appids: Vec<i64>,
+    /// App name looked up in the local catalog; repeat for several apps.
+    #[arg(long)]
+    pub name: Vec<String>,
+    /// Read appids from stdin: one per line, or a JSON envelope from another command.
+    #[arg(long, default_value_t = false)]
+    pub stdin: bool,
+    /// Read app names from stdin, one per line, and look each up like `--name`.
+    #[arg(long, default_value_t = false, conflicts_with = "stdin")]
+    pub stdin_names: bool,
+    #[arg(long, default_value_t = 86_400)]
+    pub ttl_sec: i64,
+    /// Also list other apps from the same developer.
+    #[arg(long, default_value_t = false)]
+    pub siblings: bool,
+    #[arg(long, default_value_t = 20)]
+    pub siblings_limit: usize,
+    /// Include community tags from the store page (one extra request per uncached app).
+    #[arg(long, default_value_t = false)]
+    pub with_tags: bool,
+    /// Fill interface/full audio/subtitles per language from the store page table
+    /// (one extra request per uncached app).
+    #[arg(long, default_value_t = false)]
+    pub with_languages: bool,
+    /// Keep only apps that list all of these languages, as Store codes (schinese) or
+    /// display names (implies batch output).
+    #[arg(long, value_delimiter = ',')]
+    pub language: Vec<String>,
+    /// Keep only apps with full audio in all of these languages (implies batch output).
+    #[arg(long, value_delimiter = ',')]
+    pub audio_language: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct PackageArgs {
+    pub subid: i64,
     #[arg(long, default_value_t = 86_400)]
     pub ttl_sec: i64,
 }
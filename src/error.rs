@@ -1,9 +1,16 @@
 use thiserror::Error;
 
+use crate::models::DictFindItem;
+
 #[derive(Debug, Error)]
 pub enum AppError {
     #[error("invalid argument: {0}")]
     InvalidArgument(String),
+    #[error("invalid argument: {message}")]
+    AmbiguousArgument {
+        message: String,
+        candidates: Vec<DictFindItem>,
+    },
     #[error("network error: {0}")]
     Network(String),
     #[error("upstream schema changed: {0}")]
@@ -25,7 +32,7 @@ pub enum AppError {
 impl AppError {
     pub fn code(&self) -> &'static str {
         match self {
-            Self::InvalidArgument(_) => "INVALID_ARGUMENT",
+            Self::InvalidArgument(_) | Self::AmbiguousArgument { .. } => "INVALID_ARGUMENT",
             Self::Network(_) => "NETWORK",
             Self::UpstreamSchema(_) => "UPSTREAM_SCHEMA",
             Self::NotFound(_) => "NOT_FOUND",
@@ -36,6 +43,13 @@ impl AppError {
             Self::SkillInstaller(_) => "SKILL_INSTALLER",
         }
     }
+
+    pub fn candidates(&self) -> Option<&[DictFindItem]> {
+        match self {
+            Self::AmbiguousArgument { candidates, .. } => Some(candidates),
+            _ => None,
+        }
+    }
 }
 
 impl From<rusqlite::Error> for AppError {
@@ -5,6 +5,8 @@ mod output;
 mod steam;
 mod store;
 
+use std::collections::{HashMap, HashSet};
+
 use clap::Parser;
 use serde::Serialize;
 use skillinstaller::rust_embed;
@@ -13,16 +15,33 @@ use skillinstaller::{
 };
 
 use crate::cli::{
-    AppArgs, Cli, Commands, DictSubcommands, OutputFormat, SearchArgs, UserOwnedArgs,
-    UserSubcommands,
+    AppAchievementsArgs, AppArgs, AppDlcArgs, AppNewsArgs, AppPlayersArgs, AppReviewsArgs,
+    AppSubcommands, AppTagsArgs, AppTarget, CatalogSubcommands, CatalogSyncArgs, Cli, Commands,
+    DictSubcommands, OutputFormat, PackageArgs, PlayersHistoryArgs, PlayersSubcommands, SearchArgs,
+    SuggestArgs, UserOwnedArgs, UserSubcommands,
 };
 use crate::error::AppError;
 use crate::models::{
-    AppDetailsOut, DataSource, DictFindItem, DictItem, OwnedGame, SearchItem, TagFacet,
+    Achievement, AppDetailsOut, AppReview, AppReviewSummary, AppTag, DataSource, DictFindItem,
+    DictItem, ErrorBody, LanguageSupport, NewsItem, OwnedGame, PackageDetailsOut, PlayerHistory,
+    Price, ReleaseDate, ResolvedDictItem, SearchItem, SearchItemKind, StoreLocale, SuggestItem,
+    TagFacet,
+};
+use crate::output::{
+    build_pagination, clamp_limit, error_body, print_error, print_success,
+    print_success_with_warnings,
+};
+use crate::steam::{
+    CatalogInclude, ReviewFilter, ReviewQuery, SearchQuery, SearchRun, SearchWindow,
 };
-use crate::output::{build_pagination, clamp_limit, print_error, print_success};
 use crate::store::{DictKind, LocalStore};
 
+/// Upper bound on concurrent `--any-tags` searches per invocation.
+const MAX_TAG_GROUPS: usize = 8;
+
+/// Upper bound on appids per `app` batch.
+const MAX_APP_BATCH: usize = 100;
+
 #[derive(Debug, Serialize)]
 struct DictListData {
     items: Vec<DictItem>,
@@ -37,6 +56,18 @@ struct DictFindData {
 struct SearchData {
     items: Vec<SearchItem>,
     facets: Option<FacetsData>,
+    resolved: ResolvedData,
+    /// Store `total_count` before `--types` is applied.
+    upstream_total: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResolvedData {
+    tags: Vec<ResolvedDictItem>,
+    exclude_tags: Vec<ResolvedDictItem>,
+    any_tags: Vec<Vec<ResolvedDictItem>>,
+    categories: Vec<ResolvedDictItem>,
+    genres: Vec<ResolvedDictItem>,
 }
 
 #[derive(Debug, Serialize)]
@@ -44,9 +75,129 @@ struct FacetsData {
     tags: Vec<TagFacet>,
 }
 
+#[derive(Debug, Serialize)]
+struct SuggestData {
+    items: Vec<SuggestItem>,
+}
+
 #[derive(Debug, Serialize)]
 struct AppData {
     app: AppDetailsOut,
+    siblings: Option<SiblingsData>,
+}
+
+#[derive(Debug, Serialize)]
+struct AppBatchData {
+    items: Vec<AppBatchItem>,
+    /// Apps dropped by `--language`/`--audio-language`.
+    filtered_out: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct AppBatchItem {
+    appid: i64,
+    ok: bool,
+    cached: bool,
+    app: Option<AppDetailsOut>,
+    /// Why `app` is null, or, with `ok: true`, why a store-page extra is missing.
+    error: Option<ErrorBody>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReviewsData {
+    appid: i64,
+    summary: Option<AppReviewSummary>,
+    reviews: Vec<AppReview>,
+    /// Pass to `--cursor` for the next page; null when there are no more reviews.
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct NewsData {
+    appid: i64,
+    items: Vec<NewsItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct PlayersData {
+    items: Vec<PlayerCountItem>,
+    /// Whether successful counts were stored as samples (`--record`).
+    recorded: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct PlayerCountItem {
+    appid: i64,
+    ok: bool,
+    player_count: Option<i64>,
+    error: Option<ErrorBody>,
+}
+
+#[derive(Debug, Serialize)]
+struct PlayersHistoryData {
+    since: i64,
+    items: Vec<PlayerHistory>,
+}
+
+#[derive(Debug, Serialize)]
+struct AchievementsData {
+    appid: i64,
+    game_name: Option<String>,
+    /// False when STEAM_API_KEY is unset; items then only carry `name` and `percent`.
+    schema: bool,
+    items: Vec<Achievement>,
+}
+
+#[derive(Debug, Serialize)]
+struct CatalogSyncData {
+    /// `if_modified_since` sent to Steam; null for a full download.
+    since: Option<i64>,
+    synced_at: i64,
+    pages: usize,
+    upserted: usize,
+    /// Apps in the local catalog after the sync.
+    total: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct AppTagsData {
+    appid: i64,
+    items: Vec<AppTag>,
+}
+
+#[derive(Debug, Serialize)]
+struct DlcData {
+    appid: i64,
+    name: String,
+    items: Vec<DlcItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct DlcItem {
+    appid: i64,
+    ok: bool,
+    cached: bool,
+    name: Option<String>,
+    is_free: bool,
+    price: Option<Price>,
+    release_date: Option<ReleaseDate>,
+    error: Option<ErrorBody>,
+}
+
+#[derive(Debug, Serialize)]
+struct SiblingsData {
+    /// Null when appdetails lists no developer; `items` is then empty.
+    developer: Option<String>,
+    items: Vec<SearchItem>,
+    /// Null when the store page names no franchise.
+    franchise: Option<FranchiseSiblings>,
+}
+
+#[derive(Debug, Serialize)]
+struct FranchiseSiblings {
+    name: String,
+    /// Apps from a Store title search for the franchise name.
+    items: Vec<SearchItem>,
 }
 
 #[derive(Debug, Serialize)]
@@ -73,13 +224,47 @@ async fn main() {
 
 async fn run(cli: Cli, format: OutputFormat) -> Result<(), AppError> {
     let store = LocalStore::open()?;
+    let locale = parse_store_locale(cli.cc.as_deref(), &cli.lang)?;
 
     match cli.command {
         Commands::Tags(cmd) => handle_dict(format, &store, DictKind::Tags, cmd.action),
         Commands::Genres(cmd) => handle_dict(format, &store, DictKind::Genres, cmd.action),
         Commands::Categories(cmd) => handle_dict(format, &store, DictKind::Categories, cmd.action),
-        Commands::Search(args) => handle_search(format, args).await,
-        Commands::App(args) => handle_app(format, &store, args).await,
+        Commands::Search(args) => handle_search(format, &store, &locale, *args).await,
+        Commands::Suggest(args) => handle_suggest(format, &locale, args).await,
+        Commands::App(cmd) => match cmd.action {
+            Some(AppSubcommands::Reviews(args)) => {
+                handle_app_reviews(format, &store, &locale, args).await
+            }
+            Some(AppSubcommands::News(args)) => handle_app_news(format, &store, args).await,
+            Some(AppSubcommands::Dlc(args)) => handle_app_dlc(format, &store, &locale, args).await,
+            Some(AppSubcommands::Tags(args)) => {
+                handle_app_tags(format, &store, &locale, args).await
+            }
+            Some(AppSubcommands::Achievements(args)) => {
+                handle_app_achievements(format, &store, &locale, args).await
+            }
+            Some(AppSubcommands::Players(cmd)) => match cmd.action {
+                Some(PlayersSubcommands::History(args)) => {
+                    handle_players_history(format, &store, args)
+                }
+                None => handle_app_players(format, &store, cmd.current).await,
+            },
+            None => handle_app(format, &store, &locale, cmd.details).await,
+        },
+        Commands::Package(args) => handle_package(format, &store, &locale, args).await,
+        Commands::Catalog(cmd) => match cmd.action {
+            CatalogSubcommands::Sync(args) => handle_catalog_sync(format, &store, args).await,
+            CatalogSubcommands::List(args) => {
+                handle_dict(format, &store, DictKind::Apps, DictSubcommands::List(args))
+            }
+            CatalogSubcommands::Find(args) => {
+                handle_dict(format, &store, DictKind::Apps, DictSubcommands::Find(args))
+            }
+        },
+        Commands::Players(cmd) => match cmd.action {
+            PlayersSubcommands::History(args) => handle_players_history(format, &store, args),
+        },
         Commands::User(cmd) => match cmd.action {
             UserSubcommands::Owned(args) => handle_user_owned(format, args).await,
         },
@@ -138,21 +323,144 @@ fn handle_dict(
     }
 }
 
-async fn handle_search(format: OutputFormat, args: SearchArgs) -> Result<(), AppError> {
+async fn handle_search(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    args: SearchArgs,
+) -> Result<(), AppError> {
     let limit = clamp_limit(args.limit);
     let offset = args.offset;
-    let tags = parse_tags_csv(&args.tags)?;
+    let resolved_tags =
+        resolve_optional_csv(store, DictKind::Tags, "--tags", args.tags.as_deref())?;
+    let resolved_exclude_tags = resolve_optional_csv(
+        store,
+        DictKind::Tags,
+        "--exclude-tags",
+        args.exclude_tags.as_deref(),
+    )?;
+    let resolved_categories = resolve_optional_csv(
+        store,
+        DictKind::Categories,
+        "--categories",
+        args.categories.as_deref(),
+    )?;
+    let resolved_genres =
+        resolve_optional_csv(store, DictKind::Genres, "--genres", args.genres.as_deref())?;
+    if args.any_tags.len() > MAX_TAG_GROUPS {
+        return Err(AppError::InvalidArgument(format!(
+            "--any-tags supports at most {MAX_TAG_GROUPS} groups"
+        )));
+    }
+    let resolved_any_tags = args
+        .any_tags
+        .iter()
+        .map(|group| resolve_dict_csv(store, DictKind::Tags, "--any-tags", group))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let (items, facets) =
-        steam::search_store(&tags, args.term.as_deref(), limit, offset, args.with_facets).await?;
-    let original_len = items.len();
-    let items = items.into_iter().take(limit).collect::<Vec<_>>();
-    let mut pagination = build_pagination(limit, offset, items.len(), None);
-    pagination.has_more = original_len > items.len() || pagination.has_more;
+    let mut tags = parse_resolved_ids(&resolved_tags)?;
+    for tagid in genre_tag_ids(store, &resolved_genres)? {
+        if !tags.contains(&tagid) {
+            tags.push(tagid);
+        }
+    }
+
+    let query = SearchQuery {
+        locale: locale.clone(),
+        tags,
+        exclude_tags: parse_resolved_ids(&resolved_exclude_tags)?,
+        term: args.term,
+        developer: args.developer,
+        publisher: args.publisher,
+        categories: parse_resolved_ids(&resolved_categories)?,
+        max_price: args.max_price.as_deref().map(parse_max_price).transpose()?,
+        specials: args.specials,
+        os: args.os.into_iter().map(Into::into).collect(),
+        hide_free_to_play: args.hide_free_to_play,
+        early_access: args.early_access,
+        coming_soon: args.coming_soon,
+        sort: args.sort.into(),
+        types: args.types.into_iter().map(Into::into).collect(),
+        languages: args
+            .language
+            .iter()
+            .map(|lang| lang.trim().to_lowercase())
+            .filter(|lang| !lang.is_empty())
+            .collect(),
+    };
+
+    let window = match (args.all, args.max_results) {
+        (true, _) => SearchWindow::Pages {
+            max_results: steam::SEARCH_ALL_CAP,
+        },
+        (false, Some(0)) => {
+            return Err(AppError::InvalidArgument(
+                "--max-results must be at least 1".to_string(),
+            ));
+        }
+        (false, Some(max_results)) => SearchWindow::Pages { max_results },
+        (false, None) => SearchWindow::Single { limit },
+    };
+
+    let any_mode = !resolved_any_tags.is_empty();
+    let queries = if any_mode {
+        let mut queries = Vec::with_capacity(resolved_any_tags.len());
+        for group in &resolved_any_tags {
+            let mut group_query = query.clone();
+            for tagid in parse_resolved_ids(group)? {
+                if !group_query.tags.contains(&tagid) {
+                    group_query.tags.push(tagid);
+                }
+            }
+            queries.push(group_query);
+        }
+        queries
+    } else {
+        vec![query]
+    };
+
+    let (run, cached) = cached_search(
+        store,
+        &queries,
+        any_mode,
+        window,
+        offset,
+        args.with_facets,
+        args.ttl_sec,
+    )
+    .await?;
+
+    let page_limit = match window {
+        SearchWindow::Single { limit } => limit,
+        SearchWindow::Pages { max_results } => max_results,
+    };
+    // `--types` is applied to the fetched rows, so the Store's count no longer
+    // describes the returned set; `has_more` and offsets stay in upstream rows.
+    let total = if queries[0].types.is_empty() {
+        run.total
+    } else {
+        None
+    };
+    let mut pagination = build_pagination(page_limit, offset, run.items.len(), total);
+    pagination.has_more = run.has_more;
+    pagination.upstream_pages = Some(run.pages);
+
+    let facets = run
+        .facets
+        .map(|tags| name_tag_facets(store, tags))
+        .transpose()?;
 
     let data = SearchData {
-        items,
+        items: run.items,
         facets: facets.map(|tags| FacetsData { tags }),
+        resolved: ResolvedData {
+            tags: resolved_tags,
+            exclude_tags: resolved_exclude_tags,
+            any_tags: resolved_any_tags,
+            categories: resolved_categories,
+            genres: resolved_genres,
+        },
+        upstream_total: run.total,
     };
 
     print_success(
@@ -160,172 +468,1668 @@ async fn handle_search(format: OutputFormat, args: SearchArgs) -> Result<(), App
         data,
         Some(pagination),
         DataSource::SteamStore,
-        false,
+        cached,
         |d| print_search_human(&d.items, d.facets.as_ref()),
     );
 
     Ok(())
 }
 
-async fn handle_app(
-    format: OutputFormat,
+/// Runs a search through `search_cache`, returning the run and whether it was cached.
+async fn cached_search(
     store: &LocalStore,
-    args: AppArgs,
-) -> Result<(), AppError> {
+    queries: &[SearchQuery],
+    any_mode: bool,
+    window: SearchWindow,
+    offset: usize,
+    with_facets: bool,
+    ttl_sec: i64,
+) -> Result<(SearchRun, bool), AppError> {
     let now = now_unix();
-    let min_ts = now.saturating_sub(args.ttl_sec.max(0));
+    let min_ts = now.saturating_sub(ttl_sec.max(0));
+    let cache_key = format!(
+        "{}{}",
+        if any_mode { "any|" } else { "" },
+        steam::search_cache_key(queries, window, offset, with_facets)?
+    );
+
+    if let Some(payload) = store.get_cached_search(&cache_key, min_ts)? {
+        let run: SearchRun = serde_json::from_str(&payload)
+            .map_err(|e| AppError::Database(format!("corrupt search cache entry: {e}")))?;
+        return Ok((run, true));
+    }
 
-    let (raw_json, cached) = if let Some(cached_raw) = store.get_cached_app(args.appid, min_ts)? {
-        (cached_raw, true)
+    let run = if any_mode {
+        steam::search_store_any(queries, window, offset, with_facets).await?
     } else {
-        let fresh = steam::fetch_appdetails_json(args.appid).await?;
-        store.put_cached_app(args.appid, &fresh, now)?;
-        (fresh, false)
+        steam::run_search(&queries[0], window, offset, with_facets).await?
     };
+    let payload = serde_json::to_string(&run).map_err(|e| AppError::Internal(e.to_string()))?;
+    store.put_cached_search(&cache_key, &payload, now)?;
+    Ok((run, false))
+}
 
-    let app = steam::normalize_appdetails(args.appid, &raw_json)?;
-    let data = AppData { app };
+async fn handle_suggest(
+    format: OutputFormat,
+    locale: &StoreLocale,
+    args: SuggestArgs,
+) -> Result<(), AppError> {
+    if args.text.trim().is_empty() {
+        return Err(AppError::InvalidArgument(
+            "text must not be empty".to_string(),
+        ));
+    }
+    let limit = clamp_limit(args.limit);
+    let (items, total) = steam::suggest_store(args.text.trim(), locale).await?;
+    let items = items.into_iter().take(limit).collect::<Vec<_>>();
+    let pagination = build_pagination(limit, 0, items.len(), Some(total));
+    let data = SuggestData { items };
 
-    print_success(format, data, None, DataSource::SteamStore, cached, |d| {
-        print_app_human(&d.app)
-    });
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamStore,
+        false,
+        |d| print_suggest_human(&args.text, &d.items),
+    );
 
     Ok(())
 }
 
-async fn handle_user_owned(format: OutputFormat, args: UserOwnedArgs) -> Result<(), AppError> {
-    let api_key = std::env::var("STEAM_API_KEY").map_err(|_| {
-        AppError::Unauthorized("STEAM_API_KEY is required for user owned".to_string())
-    })?;
+async fn handle_app(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    args: AppArgs,
+) -> Result<(), AppError> {
+    let mut appids = args.appids.clone();
+    appids.extend(resolve_app_names(store, &args.name)?);
+    if args.stdin || args.stdin_names {
+        let input = std::io::read_to_string(std::io::stdin())
+            .map_err(|e| AppError::Internal(format!("failed to read stdin: {e}")))?;
+        if args.stdin_names {
+            appids.extend(resolve_app_names(store, &parse_app_name_input(&input))?);
+        } else {
+            appids.extend(parse_appid_input(&input)?);
+        }
+    }
+    let appids = unique_appids(appids)?;
+    if appids.is_empty() {
+        return Err(AppError::InvalidArgument(
+            "provide at least one appid".to_string(),
+        ));
+    }
 
-    let steamid = match (args.steamid.as_deref(), args.vanity.as_deref()) {
-        (Some(id), None) => id.to_string(),
-        (None, Some(vanity)) => steam::resolve_vanity(&api_key, vanity).await?,
-        (Some(_), Some(_)) => {
+    let filter = LanguageFilter {
+        any: args.language,
+        audio: args.audio_language,
+    };
+    if args.stdin || args.stdin_names || appids.len() > 1 || !filter.is_empty() {
+        if args.siblings {
             return Err(AppError::InvalidArgument(
-                "provide only one of --steamid or --vanity".to_string(),
+                "--siblings requires a single appid".to_string(),
             ));
         }
-        (None, None) => {
-            return Err(AppError::InvalidArgument(
-                "provide --steamid or --vanity".to_string(),
-            ));
+        let extras = AppExtras {
+            tags: args.with_tags,
+            languages: args.with_languages,
+        };
+        return handle_app_batch(
+            format,
+            store,
+            locale,
+            &appids,
+            args.ttl_sec,
+            &filter,
+            extras,
+        )
+        .await;
+    }
+
+    let appid = appids[0];
+    let (mut app, mut cached) = load_appdetails(store, locale, appid, args.ttl_sec).await?;
+    let mut warnings = Vec::new();
+    if args.with_tags {
+        match load_app_tags(store, locale, appid, args.ttl_sec).await {
+            Ok((tags, tags_cached)) => {
+                app.tags = Some(tags);
+                cached &= tags_cached;
+            }
+            Err(err) => warnings.push(format!(
+                "store page tags unavailable ({}): {err}",
+                err.code()
+            )),
         }
+    }
+    if args.with_languages {
+        match load_app_languages(store, locale, appid, args.ttl_sec).await {
+            Ok((languages, languages_cached)) => {
+                app.supported_languages = languages;
+                cached &= languages_cached;
+            }
+            Err(err) => warnings.push(format!(
+                "store page language table unavailable ({}): {err}",
+                err.code()
+            )),
+        }
+    }
+    let siblings = if args.siblings {
+        let (siblings, siblings_cached) = find_siblings(
+            store,
+            locale,
+            &app,
+            clamp_limit(args.siblings_limit),
+            args.ttl_sec,
+        )
+        .await?;
+        cached &= siblings_cached;
+        Some(siblings)
+    } else {
+        None
     };
+    let data = AppData { app, siblings };
 
-    let mut items = steam::get_owned_games(&api_key, &steamid).await?;
-    items.sort_by(|a, b| b.playtime_forever_min.cmp(&a.playtime_forever_min));
+    print_success_with_warnings(
+        format,
+        data,
+        None,
+        DataSource::SteamStore,
+        cached,
+        warnings,
+        |d| {
+            print_app_human(&d.app);
+            if let Some(siblings) = &d.siblings {
+                print_siblings_human(siblings);
+            }
+        },
+    );
 
-    let limit = clamp_limit(args.limit);
-    let offset = args.offset.min(items.len());
-    let total = items.len();
-    let paged = items
+    Ok(())
+}
+
+/// `app --language`/`--audio-language` requirements; every listed language must match.
+struct LanguageFilter {
+    any: Vec<String>,
+    audio: Vec<String>,
+}
+
+impl LanguageFilter {
+    fn is_empty(&self) -> bool {
+        self.any.is_empty() && self.audio.is_empty()
+    }
+
+    fn accepts(&self, app: &AppDetailsOut) -> bool {
+        let has = |wanted: &str, audio: bool| {
+            app.supported_languages.iter().any(|lang| {
+                (!audio || lang.full_audio) && steam::language_matches(&lang.language, wanted)
+            })
+        };
+        self.any.iter().all(|wanted| has(wanted, false))
+            && self.audio.iter().all(|wanted| has(wanted, true))
+    }
+}
+
+/// Store-page data requested on top of appdetails.
+#[derive(Debug, Clone, Copy)]
+struct AppExtras {
+    tags: bool,
+    languages: bool,
+}
+
+/// Resolves cached entries first, then fetches the misses in one rate-limited batch.
+/// Failures are reported per item so one bad appid does not fail the whole call.
+/// Apps rejected by the language filter are dropped and counted in `filtered_out`.
+/// Store-page extras are only looked up for apps that are kept; a failed lookup
+/// leaves the app as appdetails returned it and is reported in the item's `error`.
+async fn handle_app_batch(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    appids: &[i64],
+    ttl_sec: i64,
+    filter: &LanguageFilter,
+    extras: AppExtras,
+) -> Result<(), AppError> {
+    let details = load_appdetails_batch(store, locale, appids, ttl_sec).await?;
+    let now = now_unix();
+    let min_ts = now.saturating_sub(ttl_sec.max(0));
+
+    let mut filtered_out = 0;
+    let details = details
         .into_iter()
-        .skip(offset)
-        .take(limit)
+        .filter(|(_, app, _)| match app {
+            Ok(app) if !filter.accepts(app) => {
+                filtered_out += 1;
+                false
+            }
+            _ => true,
+        })
+        .collect::<Vec<_>>();
+    let kept = details
+        .iter()
+        .filter(|(_, app, _)| app.is_ok())
+        .map(|(appid, _, _)| *appid)
         .collect::<Vec<_>>();
 
-    let data = OwnedData {
-        steamid,
-        items: paged,
+    let (mut tags, tag_misses) = if extras.tags {
+        let (found, misses) = load_store_page_batch(
+            &kept,
+            |appid| store.get_cached_app_tags(appid, locale, min_ts),
+            |misses| async move { steam::fetch_app_tags_batch(&misses, locale).await },
+            |appid, fresh| store.put_app_tags(appid, locale, fresh, now),
+        )
+        .await?;
+        let found = found
+            .into_iter()
+            .map(|(appid, entry)| match entry {
+                Ok(found) => Ok((appid, Ok(mark_unknown_tags(store, found)?))),
+                Err(err) => Ok((appid, Err(err))),
+            })
+            .collect::<Result<HashMap<_, _>, AppError>>()?;
+        (found, misses)
+    } else {
+        (HashMap::new(), Vec::new())
+    };
+    let (mut languages, language_misses) = if extras.languages {
+        load_store_page_batch(
+            &kept,
+            |appid| {
+                store
+                    .get_cached_app_languages(appid, locale, min_ts)?
+                    .map(|raw| parse_cached_languages(&raw))
+                    .transpose()
+            },
+            |misses| async move { steam::fetch_app_languages_batch(&misses, locale).await },
+            |appid, fresh| put_app_languages(store, locale, appid, fresh, now),
+        )
+        .await?
+    } else {
+        (HashMap::new(), Vec::new())
     };
 
-    let pagination = build_pagination(limit, offset, data.items.len(), Some(total));
+    let all_cached = details.iter().all(|(_, _, cached)| *cached)
+        && tag_misses.is_empty()
+        && language_misses.is_empty();
+    let mut items = Vec::with_capacity(details.len());
+    for (appid, app, cached) in details {
+        let entry = match app {
+            Ok(mut app) => {
+                let mut error = None;
+                match tags.remove(&appid) {
+                    Some(Ok(found)) => app.tags = Some(found),
+                    Some(Err(err)) => error = Some(error_body(&err)),
+                    None => {}
+                }
+                match languages.remove(&appid) {
+                    Some(Ok(found)) => app.supported_languages = found,
+                    Some(Err(err)) => error = error.or(Some(error_body(&err))),
+                    None => {}
+                }
+                AppBatchItem {
+                    appid,
+                    ok: true,
+                    cached: cached
+                        && !tag_misses.contains(&appid)
+                        && !language_misses.contains(&appid),
+                    app: Some(app),
+                    error,
+                }
+            }
+            Err(err) => AppBatchItem {
+                appid,
+                ok: false,
+                cached,
+                app: None,
+                error: Some(error_body(&err)),
+            },
+        };
+        items.push(entry);
+    }
+
+    let pagination = build_pagination(items.len(), 0, items.len(), Some(items.len()));
+    let data = AppBatchData {
+        items,
+        filtered_out,
+    };
 
     print_success(
         format,
         data,
         Some(pagination),
-        DataSource::SteamWebapi,
-        false,
-        |d| print_owned_human(&d.steamid, &d.items),
+        DataSource::SteamStore,
+        all_cached,
+        |d| {
+            print_app_batch_human(&d.items);
+            if d.filtered_out > 0 {
+                println!("\n{} app(s) filtered out by language", d.filtered_out);
+            }
+        },
     );
 
     Ok(())
 }
 
-fn handle_install_skill(args: InstallSkillArgs) -> Result<(), AppError> {
-    let source = load_embedded_skill::<SkillAssets>();
-
-    let result = install_interactive(source, &args)?;
+/// Appdetails through `app_cache`; returns whether the payload came from the cache.
+async fn load_appdetails(
+    store: &LocalStore,
+    locale: &StoreLocale,
+    appid: i64,
+    ttl_sec: i64,
+) -> Result<(AppDetailsOut, bool), AppError> {
+    let now = now_unix();
+    let min_ts = now.saturating_sub(ttl_sec.max(0));
 
-    print_install_result(&result);
+    if let Some(cached_raw) = store.get_cached_app(appid, locale, min_ts)? {
+        return Ok((steam::normalize_appdetails(appid, &cached_raw)?, true));
+    }
 
-    Ok(())
+    let fresh = steam::fetch_appdetails_json(appid, locale).await?;
+    let app = steam::normalize_appdetails(appid, &fresh)?;
+    store.put_cached_app(appid, locale, &fresh, now)?;
+    Ok((app, false))
 }
 
-fn parse_tags_csv(input: &str) -> Result<Vec<i64>, AppError> {
-    let mut out = Vec::new();
-    for raw in input.split(',') {
-        let trimmed = raw.trim();
-        if trimmed.is_empty() {
-            continue;
+/// Cache-first appdetails for several apps; misses are fetched in one paced batch.
+/// Returns one `(appid, result, cached)` entry per input appid, in input order.
+async fn load_appdetails_batch(
+    store: &LocalStore,
+    locale: &StoreLocale,
+    appids: &[i64],
+    ttl_sec: i64,
+) -> Result<Vec<(i64, Result<AppDetailsOut, AppError>, bool)>, AppError> {
+    let now = now_unix();
+    let min_ts = now.saturating_sub(ttl_sec.max(0));
+
+    let mut loaded: HashMap<i64, (Result<AppDetailsOut, AppError>, bool)> = HashMap::new();
+    let mut misses = Vec::new();
+    for &appid in appids {
+        match store.get_cached_app(appid, locale, min_ts)? {
+            Some(cached_raw) => {
+                let app = steam::normalize_appdetails(appid, &cached_raw);
+                loaded.insert(appid, (app, true));
+            }
+            None => misses.push(appid),
         }
-        let value = trimmed
-            .parse::<i64>()
-            .map_err(|_| AppError::InvalidArgument(format!("invalid tag id '{trimmed}'")))?;
-        out.push(value);
     }
-    if out.is_empty() {
-        return Err(AppError::InvalidArgument(
-            "--tags must include at least one numeric tag id".to_string(),
-        ));
+
+    // Only payloads that normalize are cached, so a throttled or `success: false`
+    // response is retried on the next call instead of sticking for the whole TTL.
+    for (appid, fetched) in steam::fetch_appdetails_batch(&misses, locale).await {
+        let app = match fetched
+            .and_then(|json| steam::normalize_appdetails(appid, &json).map(|app| (json, app)))
+        {
+            Ok((json, app)) => {
+                store.put_cached_app(appid, locale, &json, now)?;
+                Ok(app)
+            }
+            Err(err) => Err(err),
+        };
+        loaded.insert(appid, (app, false));
     }
-    Ok(out)
-}
 
-fn now_unix() -> i64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs() as i64
+    Ok(appids
+        .iter()
+        .map(|&appid| {
+            let (app, cached) = loaded.remove(&appid).unwrap_or_else(|| {
+                (
+                    Err(AppError::Internal("missing batch result".to_string())),
+                    false,
+                )
+            });
+            (appid, app, cached)
+        })
+        .collect())
 }
 
-fn kind_name(kind: DictKind) -> &'static str {
-    match kind {
-        DictKind::Tags => "tags",
-        DictKind::Genres => "genres",
-        DictKind::Categories => "categories",
+/// Extracts appids from stdin: either plain text (one appid per line, commas or
+/// spaces also accepted) or the JSON envelope printed by another command.
+fn parse_appid_input(input: &str) -> Result<Vec<i64>, AppError> {
+    let trimmed = input.trim();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        let value: serde_json::Value = serde_json::from_str(trimmed)
+            .map_err(|e| AppError::InvalidArgument(format!("invalid JSON on stdin: {e}")))?;
+        let mut out = Vec::new();
+        collect_appids(value.get("data").unwrap_or(&value), &mut out);
+        return Ok(out);
     }
-}
 
-fn print_dict_list_human(kind: DictKind, items: &[DictItem]) {
-    println!("{} ({})", kind_name(kind), items.len());
-    for item in items {
-        println!("{}\t{}", item.id, item.name);
-    }
+    trimmed
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.parse::<i64>()
+                .map_err(|_| AppError::InvalidArgument(format!("invalid appid '{part}' on stdin")))
+        })
+        .collect()
 }
 
-fn print_dict_find_human(kind: DictKind, query: &str, items: &[DictFindItem]) {
-    println!("{} find '{}' ({})", kind_name(kind), query, items.len());
-    for item in items {
-        println!("{}\t{}\t{:.4}", item.id, item.name, item.rank);
-    }
+/// Reads app names for `--stdin-names`: one title per line, blank lines skipped.
+fn parse_app_name_input(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
 }
 
-fn print_search_human(items: &[SearchItem], facets: Option<&FacetsData>) {
-    println!("search results ({})", items.len());
-    for item in items {
-        if let Some(price) = &item.price {
-            println!("{}\t{}\t{}", item.appid, item.name, price);
-        } else {
-            println!("{}\t{}", item.appid, item.name);
+fn collect_appids(value: &serde_json::Value, out: &mut Vec<i64>) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_appids(item, out);
+            }
+        }
+        serde_json::Value::Number(n) => out.extend(n.as_i64()),
+        serde_json::Value::Object(obj) => {
+            // Lists win over the object's own appid: `app dlc` carries the base app
+            // next to its DLC items, and `package` lists its apps under `apps`.
+            let before = out.len();
+            for key in ["items", "apps"] {
+                if let Some(inner) = obj.get(key) {
+                    collect_appids(inner, out);
+                }
+            }
+            if out.len() > before {
+                return;
+            }
+            if let Some(appid) = obj.get("appid").and_then(|v| v.as_i64()) {
+                out.push(appid);
+            } else if obj.get("kind").and_then(|v| v.as_str()) == Some("app") {
+                out.extend(obj.get("id").and_then(|v| v.as_i64()));
+            } else if let Some(inner) = obj.get("app") {
+                collect_appids(inner, out);
+            }
         }
+        _ => {}
+    }
+}
+
+/// Lists other apps by the app's first developer via a Store developer search and,
+/// when the store page names a franchise, apps found by searching the franchise title.
+/// An app without a developer or franchise just gets empty lists.
+async fn find_siblings(
+    store: &LocalStore,
+    locale: &StoreLocale,
+    app: &AppDetailsOut,
+    limit: usize,
+    ttl_sec: i64,
+) -> Result<(SiblingsData, bool), AppError> {
+    let developer = app.developers.first().cloned();
+    let (items, mut cached) = match &developer {
+        Some(developer) => {
+            let query = SearchQuery {
+                locale: locale.clone(),
+                developer: Some(developer.clone()),
+                types: vec![SearchItemKind::App],
+                ..SearchQuery::default()
+            };
+            search_siblings(store, app.appid, query, limit, ttl_sec).await?
+        }
+        None => (Vec::new(), true),
+    };
+
+    let (franchise, franchise_cached) =
+        load_app_franchise(store, locale, app.appid, ttl_sec).await?;
+    cached &= franchise_cached;
+    let franchise = match franchise {
+        Some(name) => {
+            let query = SearchQuery {
+                locale: locale.clone(),
+                term: Some(name.clone()),
+                types: vec![SearchItemKind::App],
+                ..SearchQuery::default()
+            };
+            let (items, items_cached) =
+                search_siblings(store, app.appid, query, limit, ttl_sec).await?;
+            cached &= items_cached;
+            Some(FranchiseSiblings { name, items })
+        }
+        None => None,
+    };
+
+    let siblings = SiblingsData {
+        developer,
+        items,
+        franchise,
+    };
+    Ok((siblings, cached))
+}
+
+/// Store-page franchise through the `app_franchise` cache; returns whether it came
+/// from it. A store page that redirects away counts as naming no franchise.
+async fn load_app_franchise(
+    store: &LocalStore,
+    locale: &StoreLocale,
+    appid: i64,
+    ttl_sec: i64,
+) -> Result<(Option<String>, bool), AppError> {
+    let now = now_unix();
+    let min_ts = now.saturating_sub(ttl_sec.max(0));
+    if let Some(cached) = store.get_cached_app_franchise(appid, locale, min_ts)? {
+        return Ok((cached, true));
+    }
+    let franchise = match steam::fetch_app_franchise(appid, locale).await {
+        Ok(name) => name,
+        Err(AppError::NotFound(_)) => None,
+        Err(err) => return Err(err),
+    };
+    store.put_app_franchise(appid, locale, franchise.as_deref(), now)?;
+    Ok((franchise, false))
+}
+
+async fn search_siblings(
+    store: &LocalStore,
+    appid: i64,
+    query: SearchQuery,
+    limit: usize,
+    ttl_sec: i64,
+) -> Result<(Vec<SearchItem>, bool), AppError> {
+    // Ask for one extra row so dropping the app itself still fills the limit,
+    // without going past a single Store window.
+    let window = SearchWindow::Single {
+        limit: (limit + 1).min(steam::SEARCH_PAGE_SIZE),
+    };
+    let (run, cached) = cached_search(store, &[query], false, window, 0, false, ttl_sec).await?;
+    let items = run
+        .items
+        .into_iter()
+        .filter(|item| item.id != appid)
+        .take(limit)
+        .collect();
+    Ok((items, cached))
+}
+
+/// Summaries are cached per filter combination and a fresh one is always preferred,
+/// so it stays stable across pages. A summary-only call (`--limit 0`) is answered
+/// from the cache without a request; otherwise the round trip only serves reviews.
+async fn handle_app_reviews(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    args: AppReviewsArgs,
+) -> Result<(), AppError> {
+    let appid = resolve_app_target(store, &args.target)?;
+    if args.limit > 100 {
+        return Err(AppError::InvalidArgument(
+            "--limit must be at most 100".to_string(),
+        ));
+    }
+
+    let query = ReviewQuery {
+        locale: locale.clone(),
+        language: args.language,
+        purchase_type: args.purchase_type.into(),
+        review_type: args.review_type.into(),
+        filter: args.filter.into(),
+        day_range: args.day_range,
+    };
+    if query.day_range.is_some() && query.filter != ReviewFilter::All {
+        return Err(AppError::InvalidArgument(
+            "--day-range requires --filter all".to_string(),
+        ));
+    }
+    let key = query.cache_key();
+    let now = now_unix();
+    let min_ts = now.saturating_sub(args.ttl_sec.max(0));
+
+    let cached_summary = match store.get_cached_review_summary(appid, &key, min_ts)? {
+        Some(raw) => Some(
+            serde_json::from_str::<AppReviewSummary>(&raw)
+                .map_err(|e| AppError::Internal(format!("corrupt review cache: {e}")))?,
+        ),
+        None => None,
+    };
+
+    let first_page = args.cursor.is_none();
+    if first_page && args.limit == 0 && cached_summary.is_some() {
+        let data = ReviewsData {
+            appid,
+            summary: cached_summary,
+            reviews: Vec::new(),
+            next_cursor: None,
+        };
+        print_success(format, data, None, DataSource::SteamStore, true, |d| {
+            print_reviews_human(d)
+        });
+        return Ok(());
+    }
+
+    let cursor = args.cursor.as_deref().unwrap_or("*");
+    let page = steam::fetch_app_reviews(appid, &query, cursor, args.limit).await?;
+    let summary = match (cached_summary, page.summary) {
+        (Some(cached), _) => Some(cached),
+        (None, Some(fetched)) => {
+            let payload =
+                serde_json::to_string(&fetched).map_err(|e| AppError::Internal(e.to_string()))?;
+            store.put_cached_review_summary(appid, &key, &payload, now)?;
+            Some(fetched)
+        }
+        (None, None) => None,
+    };
+
+    let returned = page.reviews.len();
+    let mut pagination = build_pagination(args.limit, 0, returned, None);
+    pagination.has_more = page.next_cursor.is_some();
+    let data = ReviewsData {
+        appid,
+        summary,
+        reviews: page.reviews,
+        next_cursor: page.next_cursor,
+    };
+
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamStore,
+        false,
+        print_reviews_human,
+    );
+
+    Ok(())
+}
+
+/// Fetched items are stored in `app_news`, and results are always read back from
+/// there, so `--offline` and TTL hits return the same shape as a fresh fetch.
+async fn handle_app_news(
+    format: OutputFormat,
+    store: &LocalStore,
+    args: AppNewsArgs,
+) -> Result<(), AppError> {
+    let appid = resolve_app_target(store, &args.target)?;
+    let count = clamp_limit(args.count);
+    let since = args.since.as_deref().map(parse_since).transpose()?;
+    let mut feeds = args
+        .feeds
+        .iter()
+        .map(|feed| feed.trim().to_lowercase())
+        .filter(|feed| !feed.is_empty())
+        .collect::<Vec<_>>();
+    feeds.sort_unstable();
+    feeds.dedup();
+
+    let query_key = format!("count={count}&feeds={}", feeds.join(","));
+    let now = now_unix();
+    let min_ts = now.saturating_sub(args.ttl_sec.max(0));
+    let cached = args.offline || store.news_fetched_since(appid, &query_key, min_ts)?;
+    if !cached {
+        let fresh = steam::get_news_for_app(appid, count, &feeds).await?;
+        store.put_news(appid, &query_key, &fresh, now)?;
+    }
+
+    let mut items = store.list_news(appid, since, &feeds, count)?;
+    if args.maxlength > 0 {
+        for item in &mut items {
+            if item.contents.chars().count() > args.maxlength {
+                item.contents = item.contents.chars().take(args.maxlength).collect();
+                item.contents.push_str("...");
+            }
+        }
+    }
+
+    let pagination = build_pagination(count, 0, items.len(), None);
+    let data = NewsData { appid, items };
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamWebapi,
+        cached,
+        print_news_human,
+    );
+
+    Ok(())
+}
+
+async fn handle_app_players(
+    format: OutputFormat,
+    store: &LocalStore,
+    args: AppPlayersArgs,
+) -> Result<(), AppError> {
+    let mut appids = args.appids;
+    appids.extend(resolve_app_names(store, &args.name)?);
+    let appids = unique_appids(appids)?;
+    let now = now_unix();
+
+    let mut items = Vec::with_capacity(appids.len());
+    for (appid, fetched) in steam::fetch_player_counts(&appids).await {
+        let entry = match fetched {
+            Ok(count) => {
+                if args.record {
+                    store.put_player_sample(appid, now, count)?;
+                }
+                PlayerCountItem {
+                    appid,
+                    ok: true,
+                    player_count: Some(count),
+                    error: None,
+                }
+            }
+            Err(err) => PlayerCountItem {
+                appid,
+                ok: false,
+                player_count: None,
+                error: Some(error_body(&err)),
+            },
+        };
+        items.push(entry);
+    }
+
+    let pagination = build_pagination(items.len(), 0, items.len(), Some(items.len()));
+    let data = PlayersData {
+        items,
+        recorded: args.record,
+    };
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamWebapi,
+        false,
+        |d| print_players_human(&d.items),
+    );
+
+    Ok(())
+}
+
+fn handle_players_history(
+    format: OutputFormat,
+    store: &LocalStore,
+    args: PlayersHistoryArgs,
+) -> Result<(), AppError> {
+    if args.window_sec <= 0 {
+        return Err(AppError::InvalidArgument(
+            "--window-sec must be positive".to_string(),
+        ));
+    }
+    let mut appids = args.appids;
+    appids.extend(resolve_app_names(store, &args.name)?);
+    let appids = unique_appids(appids)?;
+    let since = now_unix().saturating_sub(args.window_sec);
+    let items = appids
+        .iter()
+        .map(|&appid| store.player_history(appid, since))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let pagination = build_pagination(items.len(), 0, items.len(), Some(items.len()));
+    let data = PlayersHistoryData { since, items };
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::LocalDb,
+        true,
+        |d| print_players_history_human(&d.items),
+    );
+
+    Ok(())
+}
+
+async fn handle_app_tags(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    args: AppTagsArgs,
+) -> Result<(), AppError> {
+    let appid = resolve_app_target(store, &args.target)?;
+    let (items, cached) = load_app_tags(store, locale, appid, args.ttl_sec).await?;
+    let pagination = build_pagination(items.len(), 0, items.len(), Some(items.len()));
+    let data = AppTagsData { appid, items };
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamStore,
+        cached,
+        |d| print_app_tags_human(&d.items),
+    );
+
+    Ok(())
+}
+
+/// Resolves one page of the base app's `dlc` ids through the same cache-first
+/// batch as `app`, so repeated calls stay offline within the TTL.
+async fn handle_app_dlc(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    args: AppDlcArgs,
+) -> Result<(), AppError> {
+    let appid = resolve_app_target(store, &args.target)?;
+    let (base, base_cached) = load_appdetails(store, locale, appid, args.ttl_sec).await?;
+    let limit = clamp_limit(args.limit);
+    let mut seen = HashSet::new();
+    let all_ids = base
+        .dlc
+        .iter()
+        .copied()
+        .filter(|appid| seen.insert(*appid))
+        .collect::<Vec<_>>();
+    let page = all_ids
+        .iter()
+        .copied()
+        .skip(args.offset)
+        .take(limit)
+        .collect::<Vec<_>>();
+    let details = load_appdetails_batch(store, locale, &page, args.ttl_sec).await?;
+
+    let all_cached = base_cached && details.iter().all(|(_, _, cached)| *cached);
+    let items = details
+        .into_iter()
+        .map(|(appid, app, cached)| match app {
+            Ok(app) => DlcItem {
+                appid,
+                ok: true,
+                cached,
+                name: Some(app.name),
+                is_free: app.is_free,
+                price: app.price_overview,
+                release_date: app.release_date,
+                error: None,
+            },
+            Err(err) => DlcItem {
+                appid,
+                ok: false,
+                cached,
+                name: None,
+                is_free: false,
+                price: None,
+                release_date: None,
+                error: Some(error_body(&err)),
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let pagination = build_pagination(limit, args.offset, items.len(), Some(all_ids.len()));
+    let data = DlcData {
+        appid: base.appid,
+        name: base.name,
+        items,
+    };
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamStore,
+        all_cached,
+        print_dlc_human,
+    );
+
+    Ok(())
+}
+
+async fn handle_package(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    args: PackageArgs,
+) -> Result<(), AppError> {
+    let now = now_unix();
+    let min_ts = now.saturating_sub(args.ttl_sec.max(0));
+
+    let (package, cached) =
+        if let Some(cached_raw) = store.get_cached_package(args.subid, locale, min_ts)? {
+            (
+                steam::normalize_packagedetails(args.subid, &cached_raw)?,
+                true,
+            )
+        } else {
+            let fresh = steam::fetch_packagedetails_json(args.subid, locale).await?;
+            // Cache only what normalizes, so `success: false` is not kept for the TTL.
+            let package = steam::normalize_packagedetails(args.subid, &fresh)?;
+            store.put_cached_package(args.subid, locale, &fresh, now)?;
+            (package, false)
+        };
+
+    print_success(
+        format,
+        package,
+        None,
+        DataSource::SteamStore,
+        cached,
+        print_package_human,
+    );
+
+    Ok(())
+}
+
+/// Store-page tags through the `app_tags` cache; returns whether they came from it.
+async fn load_app_tags(
+    store: &LocalStore,
+    locale: &StoreLocale,
+    appid: i64,
+    ttl_sec: i64,
+) -> Result<(Vec<AppTag>, bool), AppError> {
+    let now = now_unix();
+    let min_ts = now.saturating_sub(ttl_sec.max(0));
+    let (tags, cached) = match store.get_cached_app_tags(appid, locale, min_ts)? {
+        Some(tags) => (tags, true),
+        None => {
+            let fresh = steam::fetch_app_tags(appid, locale).await?;
+            store.put_app_tags(appid, locale, &fresh, now)?;
+            (fresh, false)
+        }
+    };
+    Ok((mark_unknown_tags(store, tags)?, cached))
+}
+
+/// Store-page language table through the `app_languages` cache; returns whether it
+/// came from it.
+async fn load_app_languages(
+    store: &LocalStore,
+    locale: &StoreLocale,
+    appid: i64,
+    ttl_sec: i64,
+) -> Result<(Vec<LanguageSupport>, bool), AppError> {
+    let now = now_unix();
+    let min_ts = now.saturating_sub(ttl_sec.max(0));
+    if let Some(raw) = store.get_cached_app_languages(appid, locale, min_ts)? {
+        return Ok((parse_cached_languages(&raw)?, true));
+    }
+    let fresh = steam::fetch_app_languages(appid, locale).await?;
+    put_app_languages(store, locale, appid, &fresh, now)?;
+    Ok((fresh, false))
+}
+
+fn parse_cached_languages(raw: &str) -> Result<Vec<LanguageSupport>, AppError> {
+    serde_json::from_str(raw)
+        .map_err(|e| AppError::Internal(format!("corrupt language cache: {e}")))
+}
+
+fn put_app_languages(
+    store: &LocalStore,
+    locale: &StoreLocale,
+    appid: i64,
+    languages: &[LanguageSupport],
+    fetched_at: i64,
+) -> Result<(), AppError> {
+    let payload =
+        serde_json::to_string(languages).map_err(|e| AppError::Internal(e.to_string()))?;
+    store.put_app_languages(appid, locale, &payload, fetched_at)
+}
+
+/// Looks up per-app store-page data through its cache and fetches the misses in
+/// one paced batch. Returns each app's result and the appids that were fetched.
+async fn load_store_page_batch<T, Fut>(
+    appids: &[i64],
+    mut get_cached: impl FnMut(i64) -> Result<Option<T>, AppError>,
+    fetch: impl FnOnce(Vec<i64>) -> Fut,
+    mut put: impl FnMut(i64, &T) -> Result<(), AppError>,
+) -> Result<(HashMap<i64, Result<T, AppError>>, Vec<i64>), AppError>
+where
+    Fut: Future<Output = Vec<(i64, Result<T, AppError>)>>,
+{
+    let mut found = HashMap::new();
+    let mut misses = Vec::new();
+    for &appid in appids {
+        match get_cached(appid)? {
+            Some(cached) => {
+                found.insert(appid, Ok(cached));
+            }
+            None => misses.push(appid),
+        }
+    }
+    for (appid, fetched) in fetch(misses.clone()).await {
+        if let Ok(fresh) = &fetched {
+            put(appid, fresh)?;
+        }
+        found.insert(appid, fetched);
+    }
+    Ok((found, misses))
+}
+
+fn mark_unknown_tags(store: &LocalStore, tags: Vec<AppTag>) -> Result<Vec<AppTag>, AppError> {
+    tags.into_iter()
+        .map(|mut tag| {
+            tag.unknown = store
+                .get_dict_item(DictKind::Tags, &tag.tagid.to_string())?
+                .is_none();
+            Ok(tag)
+        })
+        .collect()
+}
+
+/// The schema needs STEAM_API_KEY; without it only global percentages are returned.
+async fn handle_app_achievements(
+    format: OutputFormat,
+    store: &LocalStore,
+    locale: &StoreLocale,
+    args: AppAchievementsArgs,
+) -> Result<(), AppError> {
+    let appid = resolve_app_target(store, &args.target)?;
+    let api_key = std::env::var("STEAM_API_KEY").ok();
+    let limit = clamp_limit(args.limit);
+
+    let (schema, percentages) = match &api_key {
+        Some(key) => {
+            let (schema, percentages) = tokio::join!(
+                steam::get_achievement_schema(key, appid, locale),
+                steam::get_global_achievement_percentages(appid)
+            );
+            (Some(schema?), percentages?)
+        }
+        None => (
+            None,
+            steam::get_global_achievement_percentages(appid).await?,
+        ),
+    };
+
+    let has_schema = schema.is_some();
+    let (game_name, definitions) = schema.unwrap_or_default();
+    let all = steam::merge_achievements(definitions, percentages);
+    let total = all.len();
+    let items = all
+        .into_iter()
+        .skip(args.offset)
+        .take(limit)
+        .collect::<Vec<_>>();
+
+    let pagination = build_pagination(limit, args.offset, items.len(), Some(total));
+    let data = AchievementsData {
+        appid,
+        game_name,
+        schema: has_schema,
+        items,
+    };
+    let warnings = if has_schema {
+        Vec::new()
+    } else {
+        vec!["STEAM_API_KEY is not set; achievements carry only name and percent".to_string()]
+    };
+    print_success_with_warnings(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamWebapi,
+        false,
+        warnings,
+        |d| print_achievements_human(d, total),
+    );
+
+    Ok(())
+}
+
+/// Incremental by default: only apps changed since the last sync of the same
+/// type selection are downloaded. The start time is stored only after the last
+/// page, so an interrupted sync is simply repeated from the previous point.
+/// `--full` buffers every page and then replaces the catalog in one transaction,
+/// so apps Steam no longer lists are dropped and an interrupted run changes nothing.
+async fn handle_catalog_sync(
+    format: OutputFormat,
+    store: &LocalStore,
+    args: CatalogSyncArgs,
+) -> Result<(), AppError> {
+    let api_key = std::env::var("STEAM_API_KEY").map_err(|_| {
+        AppError::Unauthorized("STEAM_API_KEY is required for catalog sync".to_string())
+    })?;
+    let include = CatalogInclude {
+        dlc: args.include_dlc,
+        software: args.include_software,
+    };
+    // Each type selection has its own sync point; adding DLC later must not
+    // skip DLC that changed before the previous games-only sync.
+    let state_key = format!(
+        "catalog_synced_at:dlc={}:software={}",
+        u8::from(include.dlc),
+        u8::from(include.software)
+    );
+    let since = if args.full {
+        None
+    } else {
+        store.get_sync_state(&state_key)?
+    };
+
+    let started_at = now_unix();
+    let mut last_appid = None;
+    let mut pages = 0usize;
+    let mut upserted = 0usize;
+    let mut full_list = Vec::new();
+    loop {
+        if pages > 0 {
+            tokio::time::sleep(steam::CATALOG_PAGE_DELAY).await;
+        }
+        let page = steam::get_app_list_page(&api_key, since, last_appid, include).await?;
+        pages += 1;
+        if args.full {
+            full_list.extend(page.apps);
+        } else {
+            upserted += store.upsert_catalog_apps(&page.apps)?;
+        }
+        match page.last_appid {
+            Some(next) if page.have_more => last_appid = Some(next),
+            _ => break,
+        }
+    }
+    if args.full {
+        upserted = store.replace_catalog_apps(&full_list)?;
+    }
+    store.set_sync_state(&state_key, started_at)?;
+
+    let data = CatalogSyncData {
+        since,
+        synced_at: started_at,
+        pages,
+        upserted,
+        total: store.count_dict(DictKind::Apps)?,
+    };
+    print_success(
+        format,
+        data,
+        None,
+        DataSource::SteamWebapi,
+        false,
+        print_catalog_sync_human,
+    );
+
+    Ok(())
+}
+
+/// Dedupes appids in input order and enforces the batch cap.
+fn unique_appids(mut appids: Vec<i64>) -> Result<Vec<i64>, AppError> {
+    let mut seen = HashSet::new();
+    appids.retain(|appid| seen.insert(*appid));
+    if appids.len() > MAX_APP_BATCH {
+        return Err(AppError::InvalidArgument(format!(
+            "at most {MAX_APP_BATCH} appids per call, got {}",
+            appids.len()
+        )));
+    }
+    Ok(appids)
+}
+
+/// Accepts unix seconds or a `YYYY-MM-DD` date (midnight UTC).
+fn parse_since(input: &str) -> Result<i64, AppError> {
+    let trimmed = input.trim();
+    if let Ok(ts) = trimmed.parse::<i64>() {
+        return Ok(ts);
+    }
+    let invalid = || {
+        AppError::InvalidArgument(format!(
+            "--since must be unix seconds or YYYY-MM-DD, got '{trimmed}'"
+        ))
+    };
+    let mut parts = trimmed.splitn(3, '-');
+    let mut next = || parts.next().and_then(|p| p.parse::<i64>().ok());
+    let (Some(year), Some(month), Some(day)) = (next(), next(), next()) else {
+        return Err(invalid());
+    };
+    if !(1970..=9999).contains(&year) || !(1..=12).contains(&month) {
+        return Err(invalid());
+    }
+    let month_days = steam::days_in_month(year as u32, month as usize);
+    if !(1..=i64::from(month_days)).contains(&day) {
+        return Err(invalid());
+    }
+    days_from_civil(year, month, day)
+        .checked_mul(86_400)
+        .ok_or_else(invalid)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+async fn handle_user_owned(format: OutputFormat, args: UserOwnedArgs) -> Result<(), AppError> {
+    let api_key = std::env::var("STEAM_API_KEY").map_err(|_| {
+        AppError::Unauthorized("STEAM_API_KEY is required for user owned".to_string())
+    })?;
+
+    let steamid = match (args.steamid.as_deref(), args.vanity.as_deref()) {
+        (Some(id), None) => id.to_string(),
+        (None, Some(vanity)) => steam::resolve_vanity(&api_key, vanity).await?,
+        (Some(_), Some(_)) => {
+            return Err(AppError::InvalidArgument(
+                "provide only one of --steamid or --vanity".to_string(),
+            ));
+        }
+        (None, None) => {
+            return Err(AppError::InvalidArgument(
+                "provide --steamid or --vanity".to_string(),
+            ));
+        }
+    };
+
+    let mut items = steam::get_owned_games(&api_key, &steamid).await?;
+    items.sort_by_key(|g| std::cmp::Reverse(g.playtime_forever_min));
+
+    let limit = clamp_limit(args.limit);
+    let offset = args.offset.min(items.len());
+    let total = items.len();
+    let paged = items
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .collect::<Vec<_>>();
+
+    let data = OwnedData {
+        steamid,
+        items: paged,
+    };
+
+    let pagination = build_pagination(limit, offset, data.items.len(), Some(total));
+
+    print_success(
+        format,
+        data,
+        Some(pagination),
+        DataSource::SteamWebapi,
+        false,
+        |d| print_owned_human(&d.steamid, &d.items),
+    );
+
+    Ok(())
+}
+
+fn handle_install_skill(args: InstallSkillArgs) -> Result<(), AppError> {
+    let source = load_embedded_skill::<SkillAssets>();
+
+    let result = install_interactive(source, &args)?;
+
+    print_install_result(&result);
+
+    Ok(())
+}
+
+fn resolve_dict_csv(
+    store: &LocalStore,
+    kind: DictKind,
+    flag: &str,
+    input: &str,
+) -> Result<Vec<ResolvedDictItem>, AppError> {
+    let mut out = Vec::new();
+    for raw in input.split(',') {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        out.push(resolve_dict_value(store, kind, flag, trimmed)?);
+    }
+    if out.is_empty() {
+        return Err(AppError::InvalidArgument(format!(
+            "{flag} must include at least one id or name"
+        )));
+    }
+    Ok(out)
+}
+
+fn resolve_optional_csv(
+    store: &LocalStore,
+    kind: DictKind,
+    flag: &str,
+    input: Option<&str>,
+) -> Result<Vec<ResolvedDictItem>, AppError> {
+    input
+        .map(|value| resolve_dict_csv(store, kind, flag, value))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Resolves a single id or name against a local dictionary.
+///
+/// Numeric input is taken as an id as-is, so ids missing from a stale seed DB still work.
+/// Names resolve on an exact (case-insensitive) match, or on a single fuzzy candidate.
+fn resolve_dict_value(
+    store: &LocalStore,
+    kind: DictKind,
+    flag: &str,
+    value: &str,
+) -> Result<ResolvedDictItem, AppError> {
+    if value.parse::<i64>().is_ok() {
+        return Ok(ResolvedDictItem {
+            input: value.to_string(),
+            id: value.to_string(),
+            name: store.get_dict_item(kind, value)?.map(|item| item.name),
+        });
+    }
+    resolve_dict_name(store, kind, flag, value)
+}
+
+/// Name half of [`resolve_dict_value`]; ambiguous or unknown names return ranked candidates.
+fn resolve_dict_name(
+    store: &LocalStore,
+    kind: DictKind,
+    flag: &str,
+    value: &str,
+) -> Result<ResolvedDictItem, AppError> {
+    let exact = store.find_dict_exact(kind, value)?;
+    let candidates = if exact.is_empty() {
+        store.find_dict(kind, value, 10, 0)?.0
+    } else {
+        exact
+            .into_iter()
+            .map(|item| DictFindItem {
+                id: item.id,
+                name: item.name,
+                rank: 0.0,
+            })
+            .collect()
+    };
+
+    if let [only] = candidates.as_slice() {
+        return Ok(ResolvedDictItem {
+            input: value.to_string(),
+            id: only.id.clone(),
+            name: Some(only.name.clone()),
+        });
+    }
+
+    let message = if candidates.is_empty() {
+        format!(
+            "'{value}' in {flag} does not match any entry in {}",
+            kind_name(kind)
+        )
+    } else {
+        format!(
+            "'{value}' in {flag} is ambiguous ({} candidates in {})",
+            candidates.len(),
+            kind_name(kind)
+        )
+    };
+    Err(AppError::AmbiguousArgument {
+        message,
+        candidates,
+    })
+}
+
+/// Resolves `--name` (and `--stdin-names` lines) against the catalog. Names are
+/// never read as appids, so titles such as "1979 Revolution" or "2048" still go
+/// through the lookup.
+fn resolve_app_name(store: &LocalStore, name: &str) -> Result<i64, AppError> {
+    if name.trim().is_empty() {
+        return Err(AppError::InvalidArgument(
+            "--name must not be empty".to_string(),
+        ));
+    }
+    if store.count_dict(DictKind::Apps)? == 0 {
+        return Err(AppError::NotFound(
+            "the app catalog is empty; run `catalog sync` first".to_string(),
+        ));
+    }
+    let item = resolve_dict_name(store, DictKind::Apps, "--name", name.trim())?;
+    item.id
+        .parse::<i64>()
+        .map_err(|_| AppError::Internal(format!("non-numeric id '{}'", item.id)))
+}
+
+fn resolve_app_names(store: &LocalStore, names: &[String]) -> Result<Vec<i64>, AppError> {
+    names
+        .iter()
+        .map(|name| resolve_app_name(store, name))
+        .collect()
+}
+
+fn resolve_app_target(store: &LocalStore, target: &AppTarget) -> Result<i64, AppError> {
+    match (target.appid, target.name.as_deref()) {
+        (Some(appid), _) => Ok(appid),
+        (None, Some(name)) => resolve_app_name(store, name),
+        (None, None) => Err(AppError::InvalidArgument(
+            "provide an appid or --name".to_string(),
+        )),
+    }
+}
+
+fn parse_resolved_ids(items: &[ResolvedDictItem]) -> Result<Vec<i64>, AppError> {
+    items
+        .iter()
+        .map(|item| {
+            item.id
+                .parse::<i64>()
+                .map_err(|_| AppError::Internal(format!("non-numeric id '{}'", item.id)))
+        })
+        .collect()
+}
+
+fn name_tag_facets(store: &LocalStore, facets: Vec<TagFacet>) -> Result<Vec<TagFacet>, AppError> {
+    facets
+        .into_iter()
+        .map(|mut facet| {
+            facet.name = store
+                .get_dict_item(DictKind::Tags, &facet.tagid.to_string())?
+                .map(|item| item.name);
+            facet.unknown = facet.name.is_none();
+            Ok(facet)
+        })
+        .collect()
+}
+
+/// The Store search has no genre filter; genres are matched through the tag of the same name.
+fn genre_tag_ids(store: &LocalStore, genres: &[ResolvedDictItem]) -> Result<Vec<i64>, AppError> {
+    let mut out = Vec::new();
+    for genre in genres {
+        let name = match &genre.name {
+            Some(name) => name.clone(),
+            None => {
+                return Err(AppError::InvalidArgument(format!(
+                    "genre id '{}' is not in the local genres dictionary",
+                    genre.id
+                )));
+            }
+        };
+        let tag = store
+            .find_dict_exact(DictKind::Tags, &name)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                AppError::InvalidArgument(format!(
+                    "genre '{name}' has no matching store tag to search by"
+                ))
+            })?;
+        let tagid = tag
+            .id
+            .parse::<i64>()
+            .map_err(|_| AppError::Internal(format!("non-numeric tag id '{}'", tag.id)))?;
+        out.push(tagid);
+    }
+    Ok(out)
+}
+
+fn parse_max_price(input: &str) -> Result<String, AppError> {
+    let trimmed = input.trim();
+    if trimmed.eq_ignore_ascii_case("free") {
+        return Ok("free".to_string());
+    }
+    match trimmed.parse::<f64>() {
+        Ok(value) if value.is_finite() && value >= 0.0 => Ok(trimmed.to_string()),
+        _ => Err(AppError::InvalidArgument(format!(
+            "--max-price must be a non-negative number or 'free', got '{trimmed}'"
+        ))),
+    }
+}
+
+fn parse_store_locale(cc: Option<&str>, lang: &str) -> Result<StoreLocale, AppError> {
+    let cc = cc
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| !value.is_empty());
+    if let Some(code) = &cc
+        && (code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()))
+    {
+        return Err(AppError::InvalidArgument(format!(
+            "--cc must be a two-letter country code, got '{code}'"
+        )));
+    }
+
+    let lang = lang.trim().to_lowercase();
+    if lang.is_empty() || !lang.chars().all(|c| c.is_ascii_alphabetic() || c == '_') {
+        return Err(AppError::InvalidArgument(format!(
+            "--lang must be a Steam language name such as 'english', got '{lang}'"
+        )));
+    }
+
+    Ok(StoreLocale { cc, lang })
+}
+
+fn now_unix() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+fn kind_name(kind: DictKind) -> &'static str {
+    match kind {
+        DictKind::Tags => "tags",
+        DictKind::Genres => "genres",
+        DictKind::Categories => "categories",
+        DictKind::Apps => "apps",
+    }
+}
+
+fn print_dict_list_human(kind: DictKind, items: &[DictItem]) {
+    println!("{} ({})", kind_name(kind), items.len());
+    for item in items {
+        println!("{}\t{}", item.id, item.name);
+    }
+}
+
+fn print_dict_find_human(kind: DictKind, query: &str, items: &[DictFindItem]) {
+    println!("{} find '{}' ({})", kind_name(kind), query, items.len());
+    for item in items {
+        println!("{}\t{}\t{:.4}", item.id, item.name, item.rank);
+    }
+}
+
+fn print_search_human(items: &[SearchItem], facets: Option<&FacetsData>) {
+    println!("search results ({})", items.len());
+    for item in items {
+        let review = item
+            .review
+            .as_ref()
+            .map(|r| match r.percent_positive {
+                Some(pct) => format!("{} ({}%)", r.summary, pct),
+                None => r.summary.clone(),
+            })
+            .unwrap_or_else(|| "-".to_string());
+        let groups = item
+            .matched_groups
+            .as_ref()
+            .map(|groups| {
+                format!(
+                    "\tgroups={}",
+                    groups
+                        .iter()
+                        .map(|g| g.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                )
+            })
+            .unwrap_or_default();
+        println!(
+            "{}\t{}\t{}\t{}\t{}\t{}{}",
+            item.kind.as_str(),
+            item.id,
+            item.name,
+            item.price.as_deref().unwrap_or("-"),
+            item.release_date.as_deref().unwrap_or("-"),
+            review,
+            groups
+        );
     }
 
     if let Some(f) = facets {
         println!("\nrelated tag facets ({})", f.tags.len());
         for tag in &f.tags {
-            println!("{}\t{}\tselected={}", tag.tagid, tag.count, tag.selected);
+            println!(
+                "{}\t{}\t{}\tselected={}",
+                tag.tagid,
+                tag.name.as_deref().unwrap_or("(unknown)"),
+                tag.count,
+                tag.selected
+            );
         }
     }
 }
 
+fn print_suggest_human(text: &str, items: &[SuggestItem]) {
+    println!("suggest '{}' ({})", text, items.len());
+    for item in items {
+        let price = item
+            .price
+            .as_ref()
+            .map(format_price)
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "{}\t{}\t{}\t{}",
+            item.appid, item.name, item.item_type, price
+        );
+    }
+}
+
+fn format_price(price: &Price) -> String {
+    let amount = format!("{}.{:02}", price.final_cents / 100, price.final_cents % 100);
+    match &price.currency {
+        Some(currency) => format!("{amount} {currency}"),
+        None => amount,
+    }
+}
+
 fn print_app_human(app: &AppDetailsOut) {
     println!("{} ({})", app.name, app.appid);
     if let Some(desc) = &app.short_description {
         println!("{}", desc);
     }
+    if let Some(app_type) = &app.app_type {
+        println!(
+            "type: {}{}",
+            app_type,
+            if app.is_free { " (free)" } else { "" }
+        );
+    }
+    if !app.developers.is_empty() {
+        println!("developers: {}", app.developers.join(", "));
+    }
+    if !app.publishers.is_empty() {
+        println!("publishers: {}", app.publishers.join(", "));
+    }
+    if let Some(date) = app.release_date.as_ref().and_then(|d| d.raw.as_deref()) {
+        println!("released: {}", date);
+    }
+    if let Some(price) = &app.price_overview {
+        println!("price: {}", format_price(price));
+    }
+    if let Some(metacritic) = &app.metacritic {
+        println!("metacritic: {}", metacritic.score);
+    }
+    if let Some(total) = app.recommendations_total {
+        println!("recommendations: {}", total);
+    }
+    if let Some(support) = &app.controller_support {
+        println!("controller support: {}", support);
+    }
+    if !app.dlc.is_empty() {
+        println!("dlc: {}", app.dlc.len());
+    }
+    if let Some(tags) = &app.tags {
+        println!(
+            "tags: {}",
+            tags.iter()
+                .map(|t| t.name.clone())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    if !app.supported_languages.is_empty() {
+        println!(
+            "languages: {}",
+            app.supported_languages
+                .iter()
+                .map(|lang| if lang.full_audio {
+                    format!("{} (audio)", lang.language)
+                } else {
+                    lang.language.clone()
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
     println!(
         "genres: {}",
         app.genres
@@ -344,6 +2148,198 @@ fn print_app_human(app: &AppDetailsOut) {
     );
 }
 
+fn print_app_batch_human(items: &[AppBatchItem]) {
+    for (idx, item) in items.iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+        match (&item.app, &item.error) {
+            (Some(app), error) => {
+                print_app_human(app);
+                if let Some(err) = error {
+                    println!(
+                        "store page data unavailable [{}]: {}",
+                        err.code, err.message
+                    );
+                }
+            }
+            (None, Some(err)) => println!("{}\terror [{}]: {}", item.appid, err.code, err.message),
+            (None, None) => println!("{}\terror", item.appid),
+        }
+    }
+}
+
+fn print_siblings_human(siblings: &SiblingsData) {
+    println!(
+        "\nmore from {} ({})",
+        siblings.developer.as_deref().unwrap_or("-"),
+        siblings.items.len()
+    );
+    print_sibling_items(&siblings.items);
+    if let Some(franchise) = &siblings.franchise {
+        println!("\nfranchise {} ({})", franchise.name, franchise.items.len());
+        print_sibling_items(&franchise.items);
+    }
+}
+
+fn print_sibling_items(items: &[SearchItem]) {
+    for item in items {
+        println!(
+            "{}\t{}\t{}",
+            item.id,
+            item.name,
+            item.release_date.as_deref().unwrap_or("-")
+        );
+    }
+}
+
+fn print_reviews_human(data: &ReviewsData) {
+    match &data.summary {
+        Some(summary) => println!(
+            "{} ({}): {} — {} positive / {} negative{}",
+            data.appid,
+            summary.total_reviews,
+            summary.review_score_desc,
+            summary.total_positive,
+            summary.total_negative,
+            summary
+                .percent_positive
+                .map(|p| format!(" ({p}%)"))
+                .unwrap_or_default()
+        ),
+        None => println!("{}: summary not cached", data.appid),
+    }
+    for review in &data.reviews {
+        let text = review.text.replace(['\n', '\r'], " ");
+        let snippet: String = text.chars().take(120).collect();
+        println!(
+            "{}\t{}\t{}h\t{}",
+            if review.voted_up { "+" } else { "-" },
+            review.language,
+            review.playtime_forever_min / 60,
+            snippet
+        );
+    }
+    if let Some(cursor) = &data.next_cursor {
+        println!("next cursor: {}", cursor);
+    }
+}
+
+fn print_news_human(data: &NewsData) {
+    println!("news for {} ({})", data.appid, data.items.len());
+    for item in &data.items {
+        println!(
+            "{}\t{}\t{}\t{}",
+            item.date,
+            item.feedlabel.as_deref().unwrap_or("-"),
+            item.title,
+            item.url.as_deref().unwrap_or("-")
+        );
+    }
+}
+
+fn print_players_human(items: &[PlayerCountItem]) {
+    for item in items {
+        match (item.player_count, &item.error) {
+            (Some(count), _) => println!("{}\t{}", item.appid, count),
+            (None, Some(err)) => println!("{}\terror [{}]: {}", item.appid, err.code, err.message),
+            (None, None) => println!("{}\terror", item.appid),
+        }
+    }
+}
+
+fn print_players_history_human(items: &[PlayerHistory]) {
+    println!("appid\tsamples\tmin\tmax\tavg");
+    for item in items {
+        let show = |v: Option<i64>| v.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string());
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            item.appid,
+            item.samples,
+            show(item.min),
+            show(item.max),
+            item.avg
+                .map(|avg| format!("{avg:.1}"))
+                .unwrap_or_else(|| "-".to_string())
+        );
+    }
+}
+
+fn print_catalog_sync_human(data: &CatalogSyncData) {
+    let mode = match data.since {
+        Some(since) => format!("changes since {since}"),
+        None => "full".to_string(),
+    };
+    println!(
+        "catalog sync ({mode}): {} apps in {} pages, {} in catalog",
+        data.upserted, data.pages, data.total
+    );
+}
+
+fn print_achievements_human(data: &AchievementsData, total: usize) {
+    println!(
+        "achievements for {} ({total})",
+        data.game_name.as_deref().unwrap_or(&data.appid.to_string()),
+    );
+    for item in &data.items {
+        println!(
+            "{}\t{}\t{}",
+            item.percent
+                .map(|p| format!("{p:.1}%"))
+                .unwrap_or_else(|| "-".to_string()),
+            item.display_name.as_deref().unwrap_or(&item.name),
+            item.description.as_deref().unwrap_or("")
+        );
+    }
+}
+
+fn print_app_tags_human(tags: &[AppTag]) {
+    for tag in tags {
+        println!(
+            "{}\t{}\t{}{}",
+            tag.tagid,
+            tag.name,
+            tag.votes,
+            if tag.unknown { "\t(unknown)" } else { "" }
+        );
+    }
+}
+
+fn print_dlc_human(data: &DlcData) {
+    println!(
+        "dlc for {} ({}): {}",
+        data.name,
+        data.appid,
+        data.items.len()
+    );
+    for item in &data.items {
+        match (&item.name, &item.error) {
+            (Some(name), _) => println!(
+                "{}\t{}\t{}",
+                item.appid,
+                name,
+                match &item.price {
+                    Some(price) => format_price(price),
+                    None if item.is_free => "free".to_string(),
+                    None => "-".to_string(),
+                }
+            ),
+            (None, Some(err)) => println!("{}\terror [{}]: {}", item.appid, err.code, err.message),
+            (None, None) => println!("{}\terror", item.appid),
+        }
+    }
+}
+
+fn print_package_human(package: &PackageDetailsOut) {
+    println!("{} (sub {})", package.name, package.subid);
+    if let Some(price) = &package.price {
+        println!("price: {}", format_price(price));
+    }
+    for app in &package.apps {
+        println!("{}\t{}", app.appid, app.name.as_deref().unwrap_or("-"));
+    }
+}
+
 fn print_owned_human(steamid: &str, games: &[OwnedGame]) {
     println!("owned games for {} ({})", steamid, games.len());
     for game in games {
@@ -355,3 +2351,129 @@ fn print_owned_human(steamid: &str, games: &[OwnedGame]) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CatalogApp;
+
+    fn catalog(names: &[(i64, &str)]) -> LocalStore {
+        let store = LocalStore::open_in_memory().unwrap();
+        let apps = names
+            .iter()
+            .map(|&(appid, name)| CatalogApp {
+                appid,
+                name: name.to_string(),
+                last_modified: None,
+            })
+            .collect::<Vec<_>>();
+        store.upsert_catalog_apps(&apps).unwrap();
+        store
+    }
+
+    #[test]
+    fn stdin_appids_come_from_text_or_envelopes() {
+        assert_eq!(
+            parse_appid_input("570\n620, 413150 ").unwrap(),
+            vec![570, 620, 413150]
+        );
+
+        let envelope = r#"{"ok":true,"data":{"items":[
+            {"kind":"app","id":570,"appid":570},
+            {"kind":"bundle","id":232},
+            {"appid":620}
+        ]}}"#;
+        assert_eq!(parse_appid_input(envelope).unwrap(), vec![570, 620]);
+
+        let dlc = r#"{"ok":true,"data":{"appid":367520,"name":"Hollow Knight","items":[
+            {"appid":1030300,"ok":true},{"appid":1030310,"ok":true}
+        ]}}"#;
+        assert_eq!(parse_appid_input(dlc).unwrap(), vec![1030300, 1030310]);
+        let package = r#"{"ok":true,"data":{"subid":54029,"name":"Bundle","apps":[
+            {"appid":413150,"name":"Stardew Valley"},{"appid":620,"name":"Portal 2"}
+        ]}}"#;
+        assert_eq!(parse_appid_input(package).unwrap(), vec![413150, 620]);
+        // Achievement items have no appid, so the app itself is used.
+        let achievements = r#"{"ok":true,"data":{"appid":620,"items":[{"name":"ACH_1"}]}}"#;
+        assert_eq!(parse_appid_input(achievements).unwrap(), vec![620]);
+        let single = r#"{"ok":true,"data":{"app":{"appid":620,"dlc":[1]},"siblings":{"items":[{"kind":"app","id":400,"appid":400}]}}}"#;
+        assert_eq!(parse_appid_input(single).unwrap(), vec![620]);
+
+        let err = parse_appid_input("570\nHollow Knight").unwrap_err();
+        assert!(matches!(err, AppError::InvalidArgument(ref m) if m.contains("'Hollow'")));
+    }
+
+    #[test]
+    fn since_accepts_unix_seconds_and_real_dates() {
+        assert_eq!(parse_since("1700000000").unwrap(), 1_700_000_000);
+        assert_eq!(parse_since("1970-01-01").unwrap(), 0);
+        assert_eq!(parse_since("2024-02-29").unwrap(), 1_709_164_800);
+
+        for input in [
+            "2024-02-31",
+            "2023-02-29",
+            "2024-04-31",
+            "2024-13-01",
+            "2024-00-10",
+            "1969-12-31",
+            "99999999999999-01-01",
+            "yesterday",
+        ] {
+            assert!(
+                matches!(parse_since(input), Err(AppError::InvalidArgument(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn stdin_names_are_one_per_line() {
+        assert_eq!(
+            parse_app_name_input("Hollow Knight\n\n  2048  \n"),
+            vec!["Hollow Knight".to_string(), "2048".to_string()]
+        );
+    }
+
+    #[test]
+    fn app_names_resolve_exact_then_unique_match() {
+        let store = catalog(&[
+            (367520, "Hollow Knight"),
+            (1030300, "Hollow Knight: Silksong"),
+            (1145360, "Hades"),
+            (1145350, "Hades II"),
+            (2048, "Portal"),
+            (4000, "2048"),
+        ]);
+
+        assert_eq!(resolve_app_name(&store, "hollow knight").unwrap(), 367520);
+        assert_eq!(resolve_app_name(&store, "silksong").unwrap(), 1030300);
+        // Names are never read as appids.
+        assert_eq!(resolve_app_name(&store, "2048").unwrap(), 4000);
+
+        // An exact title wins even when FTS also matches longer titles.
+        assert_eq!(resolve_app_name(&store, "hades").unwrap(), 1145360);
+    }
+
+    #[test]
+    fn ambiguous_and_unknown_app_names_fail_with_candidates() {
+        let store = catalog(&[
+            (367520, "Hollow Knight"),
+            (1030300, "Hollow Knight: Silksong"),
+        ]);
+
+        match resolve_app_name(&store, "hollow").unwrap_err() {
+            AppError::AmbiguousArgument { candidates, .. } => assert_eq!(candidates.len(), 2),
+            other => panic!("expected ambiguity, got {other}"),
+        }
+        match resolve_app_name(&store, "celeste").unwrap_err() {
+            AppError::AmbiguousArgument { candidates, .. } => assert!(candidates.is_empty()),
+            other => panic!("expected no match, got {other}"),
+        }
+
+        let empty = LocalStore::open_in_memory().unwrap();
+        assert!(matches!(
+            resolve_app_name(&empty, "hollow knight"),
+            Err(AppError::NotFound(_))
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -16,6 +16,7 @@ pub struct Pagination {
     pub returned: usize,
     pub has_more: bool,
     pub total: Option<usize>,
+    pub upstream_pages: Option<usize>,
 }
 
 #[derive(Debug, Serialize)]
@@ -23,12 +24,16 @@ pub struct Meta {
     pub version: &'static str,
     pub source: DataSource,
     pub cached: bool,
+    /// Degraded-result notices; omitted when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct ErrorBody {
     pub code: &'static str,
     pub message: String,
+    pub candidates: Option<Vec<DictFindItem>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -54,30 +59,306 @@ pub struct DictFindItem {
 }
 
 #[derive(Debug, Clone, Serialize)]
+pub struct ResolvedDictItem {
+    pub input: String,
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// Country and language sent with every Store request.
+#[derive(Debug, Clone)]
+pub struct StoreLocale {
+    pub cc: Option<String>,
+    pub lang: String,
+}
+
+impl Default for StoreLocale {
+    fn default() -> Self {
+        Self {
+            cc: None,
+            lang: "english".to_string(),
+        }
+    }
+}
+
+impl StoreLocale {
+    /// Country component for cache keys; empty when the Store picks the region.
+    pub fn cache_cc(&self) -> &str {
+        self.cc.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchItemKind {
+    App,
+    Package,
+    Bundle,
+}
+
+impl SearchItemKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::App => "app",
+            Self::Package => "package",
+            Self::Bundle => "bundle",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchItem {
-    pub appid: i64,
+    pub kind: SearchItemKind,
+    /// App id, package (sub) id or bundle id depending on `kind`.
+    pub id: i64,
+    /// Same as `id` for app rows and null otherwise, kept for consumers of the
+    /// older app-only result shape.
+    #[serde(default)]
+    pub appid: Option<i64>,
+    /// Every app contained in the row; a single entry for plain apps.
+    pub appids: Vec<i64>,
     pub name: String,
     pub price: Option<String>,
+    pub release_date: Option<String>,
+    pub review: Option<ReviewSummary>,
+    pub discount_percent: Option<i64>,
+    pub original_price_cents: Option<i64>,
+    pub final_price_cents: Option<i64>,
+    pub currency: Option<String>,
+    pub platforms: Platforms,
+    pub capsule_url: Option<String>,
+    pub vr_supported: bool,
+    pub vr_only: bool,
+    /// Indexes of the `--any-tags` groups this item matched; null for plain searches.
+    pub matched_groups: Option<Vec<usize>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewSummary {
+    pub summary: String,
+    pub percent_positive: Option<i64>,
+    pub total_reviews: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Platforms {
+    pub windows: bool,
+    pub mac: bool,
+    pub linux: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TagFacet {
     pub tagid: i64,
+    /// Name from the local `tags` dictionary; null when `unknown`.
+    pub name: Option<String>,
     pub count: i64,
     pub selected: bool,
+    /// The tag id is missing from the local dictionary (seed DB drift).
+    pub unknown: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Price {
+    pub currency: Option<String>,
+    pub initial_cents: i64,
+    pub final_cents: i64,
+    pub discount_percent: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SuggestItem {
+    pub appid: i64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub price: Option<Price>,
+    pub platforms: Platforms,
+    pub tiny_image: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct AppDetailsOut {
     pub appid: i64,
     pub name: String,
+    #[serde(rename = "type")]
+    pub app_type: Option<String>,
+    pub is_free: bool,
+    pub required_age: i64,
     pub short_description: Option<String>,
+    pub developers: Vec<String>,
+    pub publishers: Vec<String>,
     pub categories: Vec<DictItem>,
     pub genres: Vec<DictItem>,
-    pub supported_languages: Option<String>,
-    pub platforms: serde_json::Value,
-    pub release_date: Option<String>,
-    pub price_overview: Option<serde_json::Value>,
+    pub supported_languages: Vec<LanguageSupport>,
+    pub platforms: Platforms,
+    pub release_date: Option<ReleaseDate>,
+    pub price_overview: Option<Price>,
+    pub header_image: Option<String>,
+    pub website: Option<String>,
+    pub metacritic: Option<Metacritic>,
+    pub recommendations_total: Option<i64>,
+    pub dlc: Vec<i64>,
+    pub packages: Vec<i64>,
+    pub controller_support: Option<String>,
+    pub content_descriptors: ContentDescriptors,
+    pub screenshots: Vec<Screenshot>,
+    pub movies: Vec<Movie>,
+    /// Community tags from the store page; null unless requested with `--with-tags`.
+    pub tags: Option<Vec<AppTag>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppTag {
+    pub tagid: i64,
+    /// Name as shown on the store page, in the requested language.
+    pub name: String,
+    pub votes: i64,
+    /// The tag id is missing from the local dictionary (seed DB drift).
+    pub unknown: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageSupport {
+    /// Store display name, e.g. "Spanish - Spain".
+    pub language: String,
+    /// Only known from the store page's language table (`--with-languages`).
+    pub interface: Option<bool>,
+    pub full_audio: bool,
+    /// Only known from the store page's language table (`--with-languages`).
+    pub subtitles: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleaseDate {
+    /// Date string as shown by the Store, in the requested language.
+    pub raw: Option<String>,
+    /// `YYYY-MM-DD` when `raw` is a full English date; null for partial or localized dates.
+    pub iso_date: Option<String>,
+    pub coming_soon: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Metacritic {
+    pub score: i64,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ContentDescriptors {
+    pub ids: Vec<i64>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Screenshot {
+    pub id: i64,
+    pub thumbnail_url: Option<String>,
+    pub full_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Movie {
+    pub id: i64,
+    pub name: Option<String>,
+    pub thumbnail_url: Option<String>,
+    /// Best available video URL (mp4, then webm, then HLS/DASH manifests).
+    pub video_url: Option<String>,
+}
+
+/// `query_summary` of the appreviews endpoint for one filter combination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppReviewSummary {
+    pub review_score: i64,
+    pub review_score_desc: String,
+    pub total_positive: i64,
+    pub total_negative: i64,
+    pub total_reviews: i64,
+    pub percent_positive: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppReview {
+    pub recommendation_id: String,
+    pub author_steamid: Option<String>,
+    pub language: String,
+    pub text: String,
+    pub voted_up: bool,
+    pub votes_up: i64,
+    pub votes_funny: i64,
+    pub weighted_vote_score: f64,
+    pub comment_count: i64,
+    pub steam_purchase: bool,
+    pub received_for_free: bool,
+    pub written_during_early_access: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub playtime_forever_min: i64,
+    pub playtime_at_review_min: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NewsItem {
+    pub gid: String,
+    pub title: String,
+    pub url: Option<String>,
+    pub author: Option<String>,
+    /// Unix seconds.
+    pub date: i64,
+    pub feedlabel: Option<String>,
+    pub feedname: Option<String>,
+    pub contents: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageDetailsOut {
+    pub subid: i64,
+    pub name: String,
+    pub apps: Vec<PackageApp>,
+    pub price: Option<Price>,
+    /// Sum of the included apps bought separately, in cents.
+    pub individual_price_cents: Option<i64>,
+    pub platforms: Platforms,
+    pub release_date: Option<ReleaseDate>,
+    pub header_image: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageApp {
+    pub appid: i64,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Achievement {
+    /// API name; stable across languages.
+    pub name: String,
+    pub display_name: Option<String>,
+    pub description: Option<String>,
+    pub hidden: bool,
+    pub icon: Option<String>,
+    pub icon_gray: Option<String>,
+    /// Share of players who unlocked it; null when Steam has no global stats.
+    pub percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerHistory {
+    pub appid: i64,
+    pub samples: usize,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub avg: Option<f64>,
+    pub first_sampled_at: Option<i64>,
+    pub last_sampled_at: Option<i64>,
+}
+
+/// One row of `IStoreService/GetAppList`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogApp {
+    pub appid: i64,
+    pub name: String,
+    pub last_modified: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -24,6 +24,7 @@ pub fn build_pagination(
         returned,
         has_more,
         total,
+        upstream_pages: None,
     }
 }
 
@@ -34,9 +35,27 @@ pub fn print_success<T: Serialize>(
     source: DataSource,
     cached: bool,
     human: impl FnOnce(&T),
+) {
+    print_success_with_warnings(format, data, pagination, source, cached, Vec::new(), human);
+}
+
+/// Like `print_success`, with `meta.warnings`; human output prints them to stderr.
+pub fn print_success_with_warnings<T: Serialize>(
+    format: OutputFormat,
+    data: T,
+    pagination: Option<Pagination>,
+    source: DataSource,
+    cached: bool,
+    warnings: Vec<String>,
+    human: impl FnOnce(&T),
 ) {
     match format {
-        OutputFormat::Human => human(&data),
+        OutputFormat::Human => {
+            for warning in &warnings {
+                eprintln!("Warning: {warning}");
+            }
+            human(&data)
+        }
         OutputFormat::Json => {
             let envelope = Envelope {
                 ok: true,
@@ -46,6 +65,7 @@ pub fn print_success<T: Serialize>(
                     version: "1.0.0",
                     source,
                     cached,
+                    warnings,
                 },
                 error: None,
             };
@@ -57,10 +77,23 @@ pub fn print_success<T: Serialize>(
     }
 }
 
+pub fn error_body(error: &AppError) -> ErrorBody {
+    ErrorBody {
+        code: error.code(),
+        message: error.to_string(),
+        candidates: error.candidates().map(|c| c.to_vec()),
+    }
+}
+
 pub fn print_error(format: OutputFormat, error: AppError) {
     match format {
         OutputFormat::Human => {
             eprintln!("Error [{}]: {}", error.code(), error);
+            if let Some(candidates) = error.candidates() {
+                for item in candidates {
+                    eprintln!("  {}\t{}", item.id, item.name);
+                }
+            }
         }
         OutputFormat::Json => {
             let envelope: Envelope<serde_json::Value> = Envelope {
@@ -71,11 +104,9 @@ pub fn print_error(format: OutputFormat, error: AppError) {
                     version: "1.0.0",
                     source: DataSource::Internal,
                     cached: false,
+                    warnings: Vec::new(),
                 },
-                error: Some(ErrorBody {
-                    code: error.code(),
-                    message: error.to_string(),
-                }),
+                error: Some(error_body(&error)),
             };
             eprintln!(
                 "{}",
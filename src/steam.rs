@@ -1,107 +1,855 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::LazyLock;
+use std::time::Duration;
+
 use regex::Regex;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tokio::task::JoinSet;
 use url::Url;
 
 use crate::error::AppError;
-use crate::models::{AppDetailsOut, DictItem, OwnedGame, SearchItem, TagFacet};
+use crate::models::{
+    Achievement, AppDetailsOut, AppReview, AppReviewSummary, AppTag, CatalogApp,
+    ContentDescriptors, DictItem, LanguageSupport, Metacritic, Movie, NewsItem, OwnedGame,
+    PackageApp, PackageDetailsOut, Platforms, Price, ReleaseDate, ReviewSummary, Screenshot,
+    SearchItem, SearchItemKind, StoreLocale, SuggestItem, TagFacet,
+};
+
+/// Tag id the Store uses for Early Access titles.
+const EARLY_ACCESS_TAG: i64 = 493;
+
+/// Category ids the Store filters under "number of players" (`category3`).
+/// Every other category id is a feature filter (`category2`).
+const PLAYER_CATEGORY_IDS: &[i64] = &[1, 2, 9, 20, 24, 27, 36, 37, 38, 39, 47, 48, 49];
+
+/// "92% of the 1,234 user reviews" in a search row's review tooltip.
+static REVIEW_TOOLTIP_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(\d+)%\D+?([\d,.]+)").expect("review tooltip regex"));
+
+/// Tag facet pairs embedded in the full search results page.
+static TAG_FACETS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"PopulateTagFacetData\(\s*(\[[^\)]*\])\s*,\s*(\[[^\)]*\])")
+        .expect("tag facets regex")
+});
+
+/// Tag list passed to `InitAppTagModal` on an app's store page.
+static APP_TAG_MODAL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)InitAppTagModal\(\s*\d+\s*,\s*(\[.*?\])\s*,").expect("app tag modal regex")
+});
+
+/// Largest `count` the Store honours for a single search window.
+pub const SEARCH_PAGE_SIZE: usize = 100;
+
+/// Result budget used by `search --all`.
+pub const SEARCH_ALL_CAP: usize = 1000;
+
+/// Delay between successive search windows so multi-page runs stay polite.
+const SEARCH_PAGE_DELAY: Duration = Duration::from_millis(750);
+
+fn append_locale_pairs(url: &mut Url, locale: &StoreLocale) {
+    let mut qp = url.query_pairs_mut();
+    qp.append_pair("l", &locale.lang);
+    if let Some(cc) = &locale.cc {
+        qp.append_pair("cc", cc);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchOs {
+    Win,
+    Mac,
+    Linux,
+}
+
+impl SearchOs {
+    pub fn as_param(self) -> &'static str {
+        match self {
+            Self::Win => "win",
+            Self::Mac => "mac",
+            Self::Linux => "linux",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchSort {
+    #[default]
+    Relevance,
+    ReleaseDate,
+    Name,
+    Price,
+    PriceDesc,
+    ReviewScore,
+}
+
+impl SearchSort {
+    pub fn as_param(self) -> Option<&'static str> {
+        match self {
+            Self::Relevance => None,
+            Self::ReleaseDate => Some("Released_DESC"),
+            Self::Name => Some("Name_ASC"),
+            Self::Price => Some("Price_ASC"),
+            Self::PriceDesc => Some("Price_DESC"),
+            Self::ReviewScore => Some("Reviews_DESC"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    pub locale: StoreLocale,
+    pub tags: Vec<i64>,
+    pub exclude_tags: Vec<i64>,
+    pub term: Option<String>,
+    pub developer: Option<String>,
+    pub publisher: Option<String>,
+    pub categories: Vec<i64>,
+    pub max_price: Option<String>,
+    pub specials: bool,
+    pub os: Vec<SearchOs>,
+    pub hide_free_to_play: bool,
+    pub early_access: bool,
+    pub coming_soon: bool,
+    pub sort: SearchSort,
+    /// Item kinds to keep; empty keeps every kind.
+    pub types: Vec<SearchItemKind>,
+    /// Store language codes for `supportedlang`; empty sends no language filter.
+    pub languages: Vec<String>,
+}
+
+impl SearchQuery {
+    fn wants(&self, kind: SearchItemKind) -> bool {
+        self.types.is_empty() || self.types.contains(&kind)
+    }
+
+    fn effective_tags(&self) -> Vec<i64> {
+        let mut tags = self.tags.clone();
+        if self.early_access && !tags.contains(&EARLY_ACCESS_TAG) {
+            tags.push(EARLY_ACCESS_TAG);
+        }
+        tags
+    }
+
+    fn append_pairs(&self, url: &mut Url) {
+        let mut qp = url.query_pairs_mut();
+        if !self.languages.is_empty() {
+            qp.append_pair("supportedlang", &self.languages.join(","));
+        }
+        let tags = self.effective_tags();
+        if !tags.is_empty() {
+            qp.append_pair("tags", &join_ids(&tags));
+        }
+        if !self.exclude_tags.is_empty() {
+            qp.append_pair("untags", &join_ids(&self.exclude_tags));
+        }
+        if let Some(t) = &self.term {
+            qp.append_pair("term", t);
+        }
+        if let Some(developer) = &self.developer {
+            qp.append_pair("developer", developer);
+        }
+        if let Some(publisher) = &self.publisher {
+            qp.append_pair("publisher", publisher);
+        }
+        let (players, features): (Vec<i64>, Vec<i64>) = self
+            .categories
+            .iter()
+            .partition(|id| PLAYER_CATEGORY_IDS.contains(id));
+        if !features.is_empty() {
+            qp.append_pair("category2", &join_ids(&features));
+        }
+        if !players.is_empty() {
+            qp.append_pair("category3", &join_ids(&players));
+        }
+        if let Some(price) = &self.max_price {
+            qp.append_pair("maxprice", price);
+        }
+        if self.specials {
+            qp.append_pair("specials", "1");
+        }
+        if !self.os.is_empty() {
+            qp.append_pair(
+                "os",
+                &self
+                    .os
+                    .iter()
+                    .map(|os| os.as_param())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+        }
+        if self.hide_free_to_play {
+            qp.append_pair("hidef2p", "1");
+        }
+        if self.coming_soon {
+            qp.append_pair("filter", "comingsoon");
+        }
+        if let Some(sort) = self.sort.as_param() {
+            qp.append_pair("sort_by", sort);
+        }
+    }
+}
+
+/// Builds a stable `search_cache` key for a search invocation.
+///
+/// Id lists are sorted and deduplicated and the term is trimmed and lowercased,
+/// so equivalent invocations share one entry regardless of argument order.
+pub fn search_cache_key(
+    queries: &[SearchQuery],
+    window: SearchWindow,
+    offset: usize,
+    with_facets: bool,
+) -> Result<String, AppError> {
+    let mut parts = Vec::with_capacity(queries.len() + 1);
+    for query in queries {
+        let mut normalized = query.clone();
+        for ids in [
+            &mut normalized.tags,
+            &mut normalized.exclude_tags,
+            &mut normalized.categories,
+        ] {
+            ids.sort_unstable();
+            ids.dedup();
+        }
+        normalized.os.sort_by_key(|os| os.as_param());
+        normalized.os.dedup();
+        normalized.types.sort_by_key(|kind| kind.as_str());
+        normalized.types.dedup();
+        for lang in &mut normalized.languages {
+            *lang = lang.trim().to_lowercase();
+        }
+        normalized.languages.sort_unstable();
+        normalized.languages.dedup();
+        normalized.term = normalized
+            .term
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty());
+
+        let mut url = Url::parse("https://store.steampowered.com/search/results")
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        normalized.append_pairs(&mut url);
+        let mut types = normalized
+            .types
+            .iter()
+            .map(|kind| kind.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        if types.is_empty() {
+            types.push('*');
+        }
+        parts.push(format!("{}&types={types}", url.query().unwrap_or_default()));
+    }
+
+    let window = match window {
+        SearchWindow::Single { limit } => format!("count={limit}"),
+        SearchWindow::Pages { max_results } => format!("max_results={max_results}"),
+    };
+    parts.push(format!(
+        "start={offset}&{window}&facets={with_facets}&l={}&cc={}",
+        queries
+            .first()
+            .map(|q| q.locale.lang.as_str())
+            .unwrap_or_default(),
+        queries
+            .first()
+            .map(|q| q.locale.cache_cc())
+            .unwrap_or_default()
+    ));
+    Ok(parts.join("|"))
+}
+
+fn join_ids(ids: &[i64]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
 
-pub async fn search_store(
-    tags: &[i64],
-    term: Option<&str>,
+/// One window of Store search rows.
+struct SearchPage {
+    items: Vec<SearchItem>,
+    facets: Option<Vec<TagFacet>>,
+    total: Option<usize>,
+}
+
+async fn search_store(
+    query: &SearchQuery,
     limit: usize,
     offset: usize,
     with_facets: bool,
-) -> Result<(Vec<SearchItem>, Option<Vec<TagFacet>>), AppError> {
+) -> Result<SearchPage, AppError> {
+    let mut page = fetch_search_page(query, limit, offset, with_facets).await?;
+    page.items.retain(|item| query.wants(item.kind));
+    Ok(page)
+}
+
+fn search_url(
+    query: &SearchQuery,
+    limit: usize,
+    offset: usize,
+    infinite_json: bool,
+) -> Result<Url, AppError> {
     let mut url = Url::parse("https://store.steampowered.com/search/results")
         .map_err(|e| AppError::Internal(e.to_string()))?;
     {
         let mut qp = url.query_pairs_mut();
-        qp.append_pair("force_infinite", "1");
-        qp.append_pair(
-            "tags",
-            &tags
-                .iter()
-                .map(|t| t.to_string())
-                .collect::<Vec<_>>()
-                .join(","),
-        );
-        qp.append_pair("supportedlang", "english");
+        if infinite_json {
+            qp.append_pair("infinite", "1");
+        } else {
+            qp.append_pair("force_infinite", "1");
+        }
         qp.append_pair("ndl", "1");
         qp.append_pair("start", &offset.to_string());
         qp.append_pair("count", &limit.to_string());
-        if let Some(t) = term {
-            qp.append_pair("term", t);
+    }
+    append_locale_pairs(&mut url, &query.locale);
+    query.append_pairs(&mut url);
+    Ok(url)
+}
+
+/// Fetches rows from the infinite-scroll JSON response (`infinite=1`, the variant
+/// that carries `results_html`; `json=1` only returns names and logos). Facets are
+/// only embedded in the full results page, so `with_facets` costs a second request.
+async fn fetch_search_page(
+    query: &SearchQuery,
+    limit: usize,
+    offset: usize,
+    with_facets: bool,
+) -> Result<SearchPage, AppError> {
+    let url = search_url(query, limit, offset, true)?;
+    let json_text = reqwest::Client::new()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let mut page = parse_search_json(&json_text, offset)?;
+
+    if with_facets && page.items.is_empty() {
+        page.facets = Some(Vec::new());
+    } else if with_facets {
+        let url = search_url(query, limit, offset, false)?;
+        let html_text = reqwest::Client::new()
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        page.facets = Some(parse_tag_facets(&html_text, &query.tags)?);
+    }
+
+    Ok(page)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SearchWindow {
+    /// One Store request of up to `limit` rows.
+    Single { limit: usize },
+    /// Successive Store requests until `max_results` unique rows are collected.
+    Pages { max_results: usize },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchRun {
+    pub items: Vec<SearchItem>,
+    pub facets: Option<Vec<TagFacet>>,
+    pub pages: usize,
+    pub has_more: bool,
+    /// Upstream `total_count`; unknown for merged `--any-tags` runs.
+    #[serde(default)]
+    pub total: Option<usize>,
+}
+
+pub async fn run_search(
+    query: &SearchQuery,
+    window: SearchWindow,
+    offset: usize,
+    with_facets: bool,
+) -> Result<SearchRun, AppError> {
+    match window {
+        SearchWindow::Single { limit } => {
+            let page = search_store(query, limit, offset, with_facets).await?;
+            let has_more = match page.total {
+                Some(total) => offset.saturating_add(limit) < total,
+                None => page.items.len() >= limit,
+            };
+            Ok(SearchRun {
+                items: page.items.into_iter().take(limit).collect(),
+                facets: page.facets,
+                pages: 1,
+                has_more,
+                total: page.total,
+            })
+        }
+        SearchWindow::Pages { max_results } => {
+            search_store_pages(query, offset, max_results, with_facets).await
+        }
+    }
+}
+
+/// Runs one search per query and merges them as an OR. Groups go through
+/// `paced_batch`, so at most `APPDETAILS_CONCURRENCY` walks run at once.
+/// The runs are combined by `merge_group_runs`.
+pub async fn search_store_any(
+    queries: &[SearchQuery],
+    window: SearchWindow,
+    offset: usize,
+    with_facets: bool,
+) -> Result<SearchRun, AppError> {
+    let groups = (0..queries.len()).collect::<Vec<_>>();
+    let fetched = paced_batch(&groups, |group| {
+        let query = queries[group].clone();
+        async move { run_search(&query, window, offset, with_facets && group == 0).await }
+    })
+    .await;
+
+    let mut runs = Vec::with_capacity(fetched.len());
+    for (group, run) in fetched {
+        runs.push((group, run?));
+    }
+    let limit = match window {
+        SearchWindow::Single { limit } => limit,
+        SearchWindow::Pages { max_results } => max_results,
+    };
+    Ok(merge_group_runs(runs, limit))
+}
+
+/// Merges per-group runs. Items are deduplicated by kind and id and ranked by their
+/// best position in any group, ties going to the earlier group, so the order is
+/// stable across runs. `matched_groups` lists the indexes of every group an item
+/// appeared in; facets come from the first group and the merged total is unknown.
+fn merge_group_runs(runs: Vec<(usize, SearchRun)>, limit: usize) -> SearchRun {
+    let mut merged: Vec<(usize, usize, SearchItem)> = Vec::new();
+    let mut positions: HashMap<(SearchItemKind, i64), usize> = HashMap::new();
+    let mut facets = None;
+    let mut pages = 0usize;
+    let mut has_more = false;
+
+    for (idx, run) in runs {
+        pages += run.pages;
+        has_more |= run.has_more;
+        if idx == 0 {
+            facets = run.facets;
+        }
+        for (pos, mut item) in run.items.into_iter().enumerate() {
+            let key = (item.kind, item.id);
+            if let Some(&slot) = positions.get(&key) {
+                let entry = &mut merged[slot];
+                entry.0 = entry.0.min(pos);
+                if let Some(groups) = entry.2.matched_groups.as_mut() {
+                    groups.push(idx);
+                }
+                continue;
+            }
+            item.matched_groups = Some(vec![idx]);
+            positions.insert(key, merged.len());
+            merged.push((pos, idx, item));
         }
     }
 
-    let html_text = reqwest::Client::new().get(url).send().await?.text().await?;
-    parse_search_html(&html_text, tags, with_facets)
+    merged.sort_by_key(|(pos, idx, _)| (*pos, *idx));
+    has_more |= merged.len() > limit;
+
+    SearchRun {
+        items: merged
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, item)| item)
+            .collect(),
+        facets,
+        pages,
+        has_more,
+        total: None,
+    }
 }
 
-pub fn parse_search_html(
-    html_text: &str,
-    selected_tags: &[i64],
+/// Walks successive `start` windows until `max_results` unique apps are collected
+/// or the Store runs out of rows. Infinite-scroll windows overlap, so rows are
+/// deduplicated by kind and id, and a window with nothing new ends the walk.
+async fn search_store_pages(
+    query: &SearchQuery,
+    offset: usize,
+    max_results: usize,
     with_facets: bool,
-) -> Result<(Vec<SearchItem>, Option<Vec<TagFacet>>), AppError> {
-    let document = Html::parse_document(html_text);
-    let row_sel = Selector::parse("a.search_result_row")
-        .map_err(|e| AppError::Internal(format!("selector parse: {e}")))?;
-    let title_sel = Selector::parse("span.title")
-        .map_err(|e| AppError::Internal(format!("selector parse: {e}")))?;
-    let price_sel = Selector::parse("div.discount_final_price, div.search_price")
-        .map_err(|e| AppError::Internal(format!("selector parse: {e}")))?;
+) -> Result<SearchRun, AppError> {
+    let mut seen = HashSet::new();
+    let mut out = SearchRun {
+        items: Vec::new(),
+        facets: None,
+        pages: 0,
+        has_more: true,
+        total: None,
+    };
+    let mut start = offset;
+
+    while out.items.len() < max_results {
+        if out.pages > 0 {
+            tokio::time::sleep(SEARCH_PAGE_DELAY).await;
+        }
+
+        let count = SEARCH_PAGE_SIZE.min(max_results - out.items.len()).max(1);
+        let page = fetch_search_page(query, count, start, with_facets && out.pages == 0).await?;
+        out.pages += 1;
+        if out.pages == 1 {
+            out.facets = page.facets;
+        }
+        if page.total.is_some() {
+            out.total = page.total;
+        }
+
+        let fetched = page.items.len();
+        let mut added = 0usize;
+        for item in page.items {
+            if !seen.insert((item.kind, item.id)) {
+                continue;
+            }
+            added += 1;
+            if query.wants(item.kind) && out.items.len() < max_results {
+                out.items.push(item);
+            }
+        }
+
+        start += fetched;
+        let past_end = out.total.is_some_and(|total| start >= total);
+        if fetched < count || added == 0 || past_end {
+            out.has_more = false;
+            break;
+        }
+    }
+
+    Ok(out)
+}
+
+/// Phrases the Store renders in place of result rows when a query matches nothing.
+const NO_RESULTS_MARKERS: &[&str] = &[
+    "search_result_no_results",
+    "No results were returned for that query",
+    "0 results match your search",
+];
+
+/// Parses the infinite-scroll response: `{ success, results_html, total_count, start }`.
+///
+/// A response without rows is only a legitimate empty page when the Store says so,
+/// either through `total_count` not reaching past `start` or an explicit "no results"
+/// marker. Anything else means the markup changed and is reported as `UpstreamSchema`.
+fn parse_search_json(json_text: &str, offset: usize) -> Result<SearchPage, AppError> {
+    let root: Value = serde_json::from_str(json_text)
+        .map_err(|e| AppError::UpstreamSchema(format!("search response is not JSON: {e}")))?;
+    let results_html = root
+        .get("results_html")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            AppError::UpstreamSchema("results_html missing in search JSON".to_string())
+        })?;
+    let total = root
+        .get("total_count")
+        .and_then(value_to_i64)
+        .and_then(|t| usize::try_from(t).ok());
+    let start = root
+        .get("start")
+        .and_then(value_to_i64)
+        .and_then(|s| usize::try_from(s).ok())
+        .unwrap_or(offset);
+
+    let items = parse_search_rows(results_html)?;
+    if items.is_empty() {
+        let past_end = total.is_some_and(|t| t <= start);
+        let marked_empty = NO_RESULTS_MARKERS
+            .iter()
+            .any(|marker| results_html.contains(marker));
+        if !past_end && !marked_empty {
+            return Err(AppError::UpstreamSchema(
+                "no search_result_row entries found in store response".to_string(),
+            ));
+        }
+    }
+
+    Ok(SearchPage {
+        items,
+        facets: None,
+        total,
+    })
+}
+
+fn parse_search_rows(html_fragment: &str) -> Result<Vec<SearchItem>, AppError> {
+    let document = Html::parse_fragment(html_fragment);
+    let row_sel = selector("a.search_result_row")?;
+    let sels = SearchRowSelectors::new()?;
 
     let mut items = Vec::new();
     for row in document.select(&row_sel) {
-        let Some(appid_raw) = row.value().attr("data-ds-appid") else {
-            continue;
-        };
-        let Ok(appid) = appid_raw.parse::<i64>() else {
+        let Some((kind, id, appids)) = parse_row_identity(row) else {
             continue;
         };
 
-        let name = row
-            .select(&title_sel)
-            .next()
-            .map(|n| n.text().collect::<String>().trim().to_string())
-            .filter(|s| !s.is_empty())
-            .unwrap_or_else(|| "Unknown".to_string());
+        items.push(parse_search_row(row, kind, id, appids, &sels));
+    }
+    Ok(items)
+}
+
+struct SearchRowSelectors {
+    title: Selector,
+    price: Selector,
+    original_price: Selector,
+    discount_pct: Selector,
+    price_block: Selector,
+    released: Selector,
+    review: Selector,
+    capsule: Selector,
+    platform_win: Selector,
+    platform_mac: Selector,
+    platform_linux: Selector,
+    vr_supported: Selector,
+    vr_required: Selector,
+}
+
+impl SearchRowSelectors {
+    fn new() -> Result<Self, AppError> {
+        Ok(Self {
+            title: selector("span.title")?,
+            price: selector("div.discount_final_price, div.search_price")?,
+            original_price: selector("div.discount_original_price, div.search_price strike")?,
+            discount_pct: selector("div.discount_pct, div.search_discount span")?,
+            price_block: selector("[data-price-final]")?,
+            released: selector("div.search_released")?,
+            review: selector("span.search_review_summary")?,
+            capsule: selector("div.search_capsule img")?,
+            platform_win: selector("span.platform_img.win")?,
+            platform_mac: selector("span.platform_img.mac")?,
+            platform_linux: selector("span.platform_img.linux")?,
+            vr_supported: selector("span.vr_supported")?,
+            vr_required: selector("span.vr_required")?,
+        })
+    }
+}
+
+fn selector(css: &str) -> Result<Selector, AppError> {
+    Selector::parse(css).map_err(|e| AppError::Internal(format!("selector parse: {e}")))
+}
+
+fn element_text(element: ElementRef<'_>) -> String {
+    element
+        .text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn first_text(row: ElementRef<'_>, sel: &Selector) -> Option<String> {
+    row.select(sel)
+        .next()
+        .map(element_text)
+        .filter(|s| !s.is_empty())
+}
+
+/// Reads the row kind and ids. Bundles and packages carry their own id plus a
+/// comma list of contained apps in `data-ds-appid`; plain apps use the first appid.
+fn parse_row_identity(row: ElementRef<'_>) -> Option<(SearchItemKind, i64, Vec<i64>)> {
+    let attrs = row.value();
+    let appids = attrs
+        .attr("data-ds-appid")
+        .map(parse_id_list)
+        .unwrap_or_default();
+
+    if let Some(id) = attrs.attr("data-ds-bundleid").and_then(parse_single_id) {
+        return Some((SearchItemKind::Bundle, id, appids));
+    }
+    if let Some(id) = attrs.attr("data-ds-packageid").and_then(parse_single_id) {
+        return Some((SearchItemKind::Package, id, appids));
+    }
+    let id = *appids.first()?;
+    Some((SearchItemKind::App, id, appids))
+}
+
+fn parse_id_list(raw: &str) -> Vec<i64> {
+    raw.split(',')
+        .filter_map(|part| part.trim().parse::<i64>().ok())
+        .collect()
+}
+
+fn parse_single_id(raw: &str) -> Option<i64> {
+    raw.trim().parse::<i64>().ok()
+}
+
+fn parse_search_row(
+    row: ElementRef<'_>,
+    kind: SearchItemKind,
+    id: i64,
+    appids: Vec<i64>,
+    sels: &SearchRowSelectors,
+) -> SearchItem {
+    let name = first_text(row, &sels.title).unwrap_or_else(|| "Unknown".to_string());
+    let price = final_price_text(row, &sels.price);
 
-        let price = row
-            .select(&price_sel)
+    let final_price_cents = row
+        .select(&sels.price_block)
+        .next()
+        .and_then(|n| n.value().attr("data-price-final"))
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .or_else(|| price.as_deref().and_then(parse_price_cents));
+    let discount_percent = first_text(row, &sels.discount_pct).and_then(|text| {
+        text.trim_start_matches('-')
+            .trim_end_matches('%')
+            .trim()
+            .parse::<i64>()
+            .ok()
+    });
+    let original_price_cents = first_text(row, &sels.original_price)
+        .as_deref()
+        .and_then(parse_price_cents)
+        .or(final_price_cents);
+    let currency = first_text(row, &sels.original_price)
+        .or_else(|| price.clone())
+        .as_deref()
+        .and_then(currency_from_price);
+
+    SearchItem {
+        kind,
+        id,
+        appid: (kind == SearchItemKind::App).then_some(id),
+        appids,
+        name,
+        price,
+        release_date: first_text(row, &sels.released),
+        review: row
+            .select(&sels.review)
             .next()
-            .map(|n| {
-                n.text()
-                    .collect::<String>()
-                    .split_whitespace()
-                    .collect::<Vec<_>>()
-                    .join(" ")
-            })
-            .filter(|s| !s.is_empty());
+            .and_then(|n| n.value().attr("data-tooltip-html"))
+            .and_then(parse_review_tooltip),
+        discount_percent,
+        original_price_cents,
+        final_price_cents,
+        currency,
+        platforms: Platforms {
+            windows: row.select(&sels.platform_win).next().is_some(),
+            mac: row.select(&sels.platform_mac).next().is_some(),
+            linux: row.select(&sels.platform_linux).next().is_some(),
+        },
+        capsule_url: row
+            .select(&sels.capsule)
+            .next()
+            .and_then(|n| n.value().attr("src"))
+            .map(|s| s.to_string()),
+        vr_supported: row.select(&sels.vr_supported).next().is_some(),
+        vr_only: row.select(&sels.vr_required).next().is_some(),
+        matched_groups: None,
+    }
+}
 
-        items.push(SearchItem { appid, name, price });
+/// Final price shown in a row. Legacy `search_price` cells hold the struck-out
+/// original and the final price together, so only the last text node is used.
+fn final_price_text(row: ElementRef<'_>, sel: &Selector) -> Option<String> {
+    row.select(sel)
+        .next()?
+        .text()
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|text| !text.is_empty())
+        .last()
+}
+
+/// Parses the review tooltip, e.g.
+/// `Very Positive<br>92% of the 1,234 user reviews for this game are positive.`
+fn parse_review_tooltip(tooltip: &str) -> Option<ReviewSummary> {
+    let mut parts = tooltip.splitn(2, "<br>");
+    let summary = parts.next()?.trim().to_string();
+    if summary.is_empty() {
+        return None;
     }
+    let detail = parts.next().unwrap_or_default();
+    let caps = REVIEW_TOOLTIP_RE.captures(detail);
 
-    if items.is_empty() {
-        return Err(AppError::UpstreamSchema(
-            "no search_result_row entries found in store response".to_string(),
-        ));
+    Some(ReviewSummary {
+        summary,
+        percent_positive: caps
+            .as_ref()
+            .and_then(|c| c.get(1))
+            .and_then(|m| m.as_str().parse::<i64>().ok()),
+        total_reviews: caps
+            .as_ref()
+            .and_then(|c| c.get(2))
+            .and_then(|m| m.as_str().replace([',', '.'], "").parse::<i64>().ok()),
+    })
+}
+
+/// Converts a localized price string ("$14.99", "14,99€", "¥ 1,200") to integer cents.
+/// A trailing separator followed by exactly two digits is read as the decimal part.
+fn parse_price_cents(text: &str) -> Option<i64> {
+    let lower = text.to_lowercase();
+    if lower.contains("free") {
+        return Some(0);
     }
 
-    let facets = if with_facets {
-        Some(parse_tag_facets(html_text, selected_tags)?)
-    } else {
-        None
-    };
+    let numeric = text
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
+        .collect::<String>();
+    let numeric = numeric.trim_matches(|c| c == '.' || c == ',');
+    if numeric.is_empty() {
+        return None;
+    }
+
+    let has_decimals = numeric
+        .rfind(['.', ','])
+        .map(|idx| numeric.len() - idx - 1 == 2)
+        .unwrap_or(false);
+    let digits = numeric.replace(['.', ','], "").parse::<i64>().ok()?;
+    Some(if has_decimals { digits } else { digits * 100 })
+}
 
-    Ok((items, facets))
+/// Best-effort mapping from the Store's price formatting to an ISO 4217 code.
+/// More specific prefixes must come before the bare symbols they contain.
+const CURRENCY_MARKERS: &[(&str, &str)] = &[
+    ("CDN$", "CAD"),
+    ("A$", "AUD"),
+    ("NZ$", "NZD"),
+    ("Mex$", "MXN"),
+    ("R$", "BRL"),
+    ("HK$", "HKD"),
+    ("NT$", "TWD"),
+    ("S$", "SGD"),
+    ("CLP$", "CLP"),
+    ("COL$", "COP"),
+    ("$U", "UYU"),
+    ("S/.", "PEN"),
+    ("CHF", "CHF"),
+    ("zł", "PLN"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("¥", "JPY"),
+    ("₩", "KRW"),
+    ("₹", "INR"),
+    ("₽", "RUB"),
+    ("₺", "TRY"),
+    ("₴", "UAH"),
+    ("₸", "KZT"),
+    ("₪", "ILS"),
+    ("₫", "VND"),
+    ("฿", "THB"),
+    ("₱", "PHP"),
+    ("₡", "CRC"),
+    ("Rp", "IDR"),
+    ("RM", "MYR"),
+    ("$", "USD"),
+];
+
+fn currency_from_price(text: &str) -> Option<String> {
+    if !text.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    CURRENCY_MARKERS
+        .iter()
+        .find(|(marker, _)| text.contains(marker))
+        .map(|(_, code)| code.to_string())
 }
 
 fn parse_tag_facets(html_text: &str, selected_tags: &[i64]) -> Result<Vec<TagFacet>, AppError> {
-    let re = Regex::new(r"PopulateTagFacetData\(\s*(\[[^\)]*\])\s*,\s*(\[[^\)]*\])")
-        .map_err(|e| AppError::Internal(e.to_string()))?;
-    let caps = re.captures(html_text).ok_or_else(|| {
+    let caps = TAG_FACETS_RE.captures(html_text).ok_or_else(|| {
         AppError::UpstreamSchema("facets block not found in search HTML".to_string())
     })?;
 
@@ -123,8 +871,10 @@ fn parse_tag_facets(html_text: &str, selected_tags: &[i64]) -> Result<Vec<TagFac
             let count = value_to_i64(&pair[1])?;
             Some(TagFacet {
                 tagid,
+                name: None,
                 count,
                 selected: selected_tags.contains(&tagid),
+                unknown: false,
             })
         })
         .collect::<Vec<_>>();
@@ -142,10 +892,174 @@ fn value_to_i64(v: &Value) -> Option<i64> {
     None
 }
 
-pub async fn fetch_appdetails_json(appid: i64) -> Result<String, AppError> {
-    let url = format!("https://store.steampowered.com/api/appdetails?appids={appid}&l=english");
-    let text = reqwest::Client::new().get(url).send().await?.text().await?;
-    Ok(text)
+/// Search-as-you-type lookup through the Store's `storesearch` API.
+pub async fn suggest_store(
+    text: &str,
+    locale: &StoreLocale,
+) -> Result<(Vec<SuggestItem>, usize), AppError> {
+    let mut url = Url::parse("https://store.steampowered.com/api/storesearch/")
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    url.query_pairs_mut().append_pair("term", text);
+    append_locale_pairs(&mut url, locale);
+
+    let json: Value = reqwest::Client::new().get(url).send().await?.json().await?;
+    let items = json
+        .get("items")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| AppError::UpstreamSchema("storesearch items missing".to_string()))?;
+
+    let out = items
+        .iter()
+        .filter_map(|item| {
+            let appid = item.get("id").and_then(value_to_i64)?;
+            let name = item.get("name")?.as_str()?.to_string();
+            Some(SuggestItem {
+                appid,
+                name,
+                item_type: item
+                    .get("type")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("app")
+                    .to_string(),
+                price: item.get("price").and_then(parse_price),
+                platforms: parse_platforms(item.get("platforms")),
+                tiny_image: item
+                    .get("tiny_image")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string()),
+            })
+        })
+        .collect::<Vec<_>>();
+    let total = json
+        .get("total")
+        .and_then(value_to_i64)
+        .and_then(|t| usize::try_from(t).ok())
+        .unwrap_or(out.len());
+
+    Ok((out, total))
+}
+
+/// Price object shared by `storesearch` (`price`) and `appdetails` (`price_overview`).
+/// `discount_percent` is only sent by appdetails, so it is derived when missing.
+fn parse_price(value: &Value) -> Option<Price> {
+    let initial_cents = value.get("initial").and_then(value_to_i64)?;
+    let final_cents = value.get("final").and_then(value_to_i64)?;
+    let discount_percent = value
+        .get("discount_percent")
+        .and_then(value_to_i64)
+        .unwrap_or_else(|| {
+            if initial_cents > 0 && final_cents < initial_cents {
+                ((initial_cents - final_cents) * 100 + initial_cents / 2) / initial_cents
+            } else {
+                0
+            }
+        });
+    Some(Price {
+        currency: value
+            .get("currency")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        initial_cents,
+        final_cents,
+        discount_percent,
+    })
+}
+
+fn parse_platforms(value: Option<&Value>) -> Platforms {
+    let flag = |key: &str| {
+        value
+            .and_then(|v| v.get(key))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    };
+    Platforms {
+        windows: flag("windows"),
+        mac: flag("mac"),
+        linux: flag("linux"),
+    }
+}
+
+pub async fn fetch_appdetails_json(appid: i64, locale: &StoreLocale) -> Result<String, AppError> {
+    let mut url = Url::parse("https://store.steampowered.com/api/appdetails")
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    url.query_pairs_mut()
+        .append_pair("appids", &appid.to_string());
+    append_locale_pairs(&mut url, locale);
+    // Throttled (429 → RATE_LIMIT) and server error pages must not reach the cache.
+    let response = reqwest::Client::new()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.text().await?)
+}
+
+/// Most appdetails requests allowed in flight during a batch.
+const APPDETAILS_CONCURRENCY: usize = 4;
+
+/// Minimum gap between starting two appdetails requests in a batch.
+const APPDETAILS_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Fetches raw appdetails for several apps with bounded concurrency and a steady
+/// request rate. Results come back in input order, one `Result` per appid.
+pub async fn fetch_appdetails_batch(
+    appids: &[i64],
+    locale: &StoreLocale,
+) -> Vec<(i64, Result<String, AppError>)> {
+    paced_batch(appids, |appid| {
+        let locale = locale.clone();
+        async move { fetch_appdetails_json(appid, &locale).await }
+    })
+    .await
+}
+
+/// Runs one request per key (an appid, or a search group index) with at most
+/// `APPDETAILS_CONCURRENCY` in flight and `APPDETAILS_INTERVAL` between starts.
+/// Results come back in input order.
+async fn paced_batch<K, T, F, Fut>(keys: &[K], fetch: F) -> Vec<(K, Result<T, AppError>)>
+where
+    K: Copy + Default + Send + 'static,
+    T: Send + 'static,
+    F: Fn(K) -> Fut,
+    Fut: Future<Output = Result<T, AppError>> + Send + 'static,
+{
+    let mut set = JoinSet::new();
+    let mut out = Vec::with_capacity(keys.len());
+
+    for (idx, &key) in keys.iter().enumerate() {
+        if set.len() >= APPDETAILS_CONCURRENCY
+            && let Some(joined) = set.join_next().await
+        {
+            out.push(joined_batch_entry(joined));
+        }
+        if idx > 0 {
+            tokio::time::sleep(APPDETAILS_INTERVAL).await;
+        }
+        let request = fetch(key);
+        set.spawn(async move { (idx, key, request.await) });
+    }
+    while let Some(joined) = set.join_next().await {
+        out.push(joined_batch_entry(joined));
+    }
+
+    out.sort_by_key(|(idx, _, _)| *idx);
+    out.into_iter()
+        .map(|(_, key, fetched)| (key, fetched))
+        .collect()
+}
+
+type BatchEntry<K, T> = (usize, K, Result<T, AppError>);
+
+fn joined_batch_entry<K: Default, T>(
+    joined: Result<BatchEntry<K, T>, tokio::task::JoinError>,
+) -> BatchEntry<K, T> {
+    joined.unwrap_or_else(|e| {
+        (
+            usize::MAX,
+            K::default(),
+            Err(AppError::Internal(e.to_string())),
+        )
+    })
 }
 
 pub fn normalize_appdetails(appid: i64, raw_json: &str) -> Result<AppDetailsOut, AppError> {
@@ -174,46 +1088,988 @@ pub fn normalize_appdetails(appid: i64, raw_json: &str) -> Result<AppDetailsOut,
         .to_string();
 
     let categories = parse_id_description_list(data.get("categories"));
+    let developers = parse_string_list(data.get("developers"));
+    let publishers = parse_string_list(data.get("publishers"));
     let genres = parse_id_description_list(data.get("genres"));
 
     let out = AppDetailsOut {
         appid,
         name,
+        app_type: json_string(data.get("type")),
+        is_free: data
+            .get("is_free")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        required_age: data
+            .get("required_age")
+            .and_then(value_to_i64)
+            .unwrap_or_default(),
         short_description: data
             .get("short_description")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string()),
+        developers,
+        publishers,
         categories,
         genres,
         supported_languages: data
             .get("supported_languages")
             .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        platforms: data.get("platforms").cloned().unwrap_or(Value::Null),
-        release_date: data
-            .get("release_date")
-            .and_then(|v| v.get("date"))
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        price_overview: data.get("price_overview").cloned(),
+            .map(parse_supported_languages)
+            .unwrap_or_default(),
+        platforms: parse_platforms(data.get("platforms")),
+        release_date: data.get("release_date").map(parse_release_date),
+        price_overview: data.get("price_overview").and_then(parse_price),
+        header_image: json_string(data.get("header_image")),
+        website: json_string(data.get("website")),
+        metacritic: data.get("metacritic").and_then(|m| {
+            Some(Metacritic {
+                score: m.get("score").and_then(value_to_i64)?,
+                url: json_string(m.get("url")),
+            })
+        }),
+        recommendations_total: data
+            .get("recommendations")
+            .and_then(|r| r.get("total"))
+            .and_then(value_to_i64),
+        dlc: parse_i64_list(data.get("dlc")),
+        packages: parse_i64_list(data.get("packages")),
+        controller_support: json_string(data.get("controller_support")),
+        content_descriptors: ContentDescriptors {
+            ids: parse_i64_list(data.get("content_descriptors").and_then(|c| c.get("ids"))),
+            notes: json_string(data.get("content_descriptors").and_then(|c| c.get("notes"))),
+        },
+        screenshots: parse_screenshots(data.get("screenshots")),
+        movies: parse_movies(data.get("movies")),
+        tags: None,
     };
 
     Ok(out)
 }
 
-fn parse_id_description_list(value: Option<&Value>) -> Vec<DictItem> {
-    let Some(Value::Array(items)) = value else {
-        return Vec::new();
+fn parse_release_date(value: &Value) -> ReleaseDate {
+    let raw = json_string(value.get("date"));
+    ReleaseDate {
+        iso_date: raw.as_deref().and_then(english_date_to_iso),
+        raw,
+        coming_soon: value
+            .get("coming_soon")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+    }
+}
+
+const MONTHS: &[&str] = &[
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// Parses the two English layouts the Store uses ("7 Aug, 2018" and "Aug 7, 2018").
+/// Partial dates such as "Q3 2026" or "Coming soon" have no ISO form, and neither
+/// do dates localized by a non-English `--lang` ("26. Feb. 2016").
+fn english_date_to_iso(raw: &str) -> Option<String> {
+    let cleaned = raw.replace(',', " ");
+    let parts: Vec<&str> = cleaned.split_whitespace().collect();
+    let [a, b, year] = parts.as_slice() else {
+        return None;
+    };
+    let (day, month) = match (a.parse::<u32>(), b.parse::<u32>()) {
+        (Ok(day), Err(_)) => (day, *b),
+        (Err(_), Ok(day)) => (day, *a),
+        _ => return None,
     };
+    let month_key = month.get(..3)?.to_ascii_lowercase();
+    let month = MONTHS.iter().position(|m| *m == month_key)? + 1;
+    let year = year.parse::<u32>().ok()?;
+    if year < 1970 || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    Some(format!("{year:04}-{month:02}-{day:02}"))
+}
 
-    items
-        .iter()
-        .filter_map(|item| {
-            let id = item.get("id")?.as_i64()?.to_string();
-            let name = item.get("description")?.as_str()?.to_string();
-            Some(DictItem { id, name })
-        })
-        .collect()
+/// Length of `month` (1-based) in the Gregorian calendar.
+pub fn days_in_month(year: u32, month: usize) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses Steam's `supported_languages` markup, e.g.
+/// `English<strong>*</strong>, French<br><strong>*</strong>languages with full audio support`.
+/// A trailing `*` marks full audio; the footnote after `<br>` is dropped. Interface
+/// and subtitle support are not part of this string, so they stay null.
+fn parse_supported_languages(raw: &str) -> Vec<LanguageSupport> {
+    let list = raw.split("<br").next().unwrap_or_default();
+    let mut text = String::with_capacity(list.len());
+    let mut in_tag = false;
+    for ch in list.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    let text = text.replace("[b]", "").replace("[/b]", "");
+
+    text.split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            let full_audio = part.ends_with('*');
+            let language = part.trim_end_matches('*').trim();
+            if language.is_empty() {
+                return None;
+            }
+            Some(LanguageSupport {
+                language: language.to_string(),
+                interface: None,
+                full_audio,
+                subtitles: None,
+            })
+        })
+        .collect()
+}
+
+/// Store language codes (as taken by `supportedlang` and `search --language`) whose
+/// English display name is not just the capitalized code.
+const STORE_LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("schinese", "simplified chinese"),
+    ("tchinese", "traditional chinese"),
+    ("koreana", "korean"),
+    ("spanish", "spanish - spain"),
+    ("latam", "spanish - latin america"),
+    ("portuguese", "portuguese - portugal"),
+    ("brazilian", "portuguese - brazil"),
+];
+
+/// Matches a user-supplied language against a Store display name, case-insensitively.
+/// `wanted` may be a display name or a Store language code ("schinese" matches
+/// "Simplified Chinese"). A bare base name also matches regional variants ("spanish"
+/// matches "Spanish - Spain" and "Spanish - Latin America").
+pub fn language_matches(display_name: &str, wanted: &str) -> bool {
+    let name = display_name.to_lowercase();
+    let wanted = wanted.trim().to_lowercase();
+    let by_code = STORE_LANGUAGE_NAMES
+        .iter()
+        .any(|(code, display)| *code == wanted && *display == name);
+    by_code
+        || name == wanted
+        || name
+            .split_once(" - ")
+            .is_some_and(|(base, _)| base.trim() == wanted)
+}
+
+pub async fn fetch_packagedetails_json(
+    subid: i64,
+    locale: &StoreLocale,
+) -> Result<String, AppError> {
+    let mut url = Url::parse("https://store.steampowered.com/api/packagedetails")
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    url.query_pairs_mut()
+        .append_pair("packageids", &subid.to_string());
+    append_locale_pairs(&mut url, locale);
+
+    let response = reqwest::Client::new()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(response.text().await?)
+}
+
+pub fn normalize_packagedetails(subid: i64, raw_json: &str) -> Result<PackageDetailsOut, AppError> {
+    let root: Value =
+        serde_json::from_str(raw_json).map_err(|e| AppError::UpstreamSchema(e.to_string()))?;
+    let obj = root.get(subid.to_string()).ok_or_else(|| {
+        AppError::UpstreamSchema("package id key missing in packagedetails".to_string())
+    })?;
+
+    let success = obj
+        .get("success")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !success {
+        return Err(AppError::NotFound(format!("package {subid} not found")));
+    }
+
+    let data = obj
+        .get("data")
+        .ok_or_else(|| AppError::UpstreamSchema("packagedetails data missing".to_string()))?;
+
+    let apps = data
+        .get("apps")
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    Some(PackageApp {
+                        appid: item.get("id").and_then(value_to_i64)?,
+                        name: json_string(item.get("name")),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(PackageDetailsOut {
+        subid,
+        name: json_string(data.get("name")).unwrap_or_else(|| "Unknown".to_string()),
+        apps,
+        price: data.get("price").and_then(parse_price),
+        individual_price_cents: data
+            .get("price")
+            .and_then(|p| p.get("individual"))
+            .and_then(value_to_i64),
+        platforms: parse_platforms(data.get("platforms")),
+        release_date: data.get("release_date").map(parse_release_date),
+        header_image: json_string(data.get("header_image")),
+    })
+}
+
+/// Non-empty string field; the Store uses both `null` and `""` for missing values.
+fn json_string(value: Option<&Value>) -> Option<String> {
+    value
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn parse_i64_list(value: Option<&Value>) -> Vec<i64> {
+    let Some(Value::Array(items)) = value else {
+        return Vec::new();
+    };
+    items.iter().filter_map(value_to_i64).collect()
+}
+
+fn parse_screenshots(value: Option<&Value>) -> Vec<Screenshot> {
+    let Some(Value::Array(items)) = value else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            Some(Screenshot {
+                id: item.get("id").and_then(value_to_i64)?,
+                thumbnail_url: json_string(item.get("path_thumbnail")),
+                full_url: json_string(item.get("path_full")),
+            })
+        })
+        .collect()
+}
+
+fn parse_movies(value: Option<&Value>) -> Vec<Movie> {
+    let Some(Value::Array(items)) = value else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            let video_url = json_string(item.get("mp4").and_then(|v| v.get("max")))
+                .or_else(|| json_string(item.get("webm").and_then(|v| v.get("max"))))
+                .or_else(|| json_string(item.get("hls_h264")))
+                .or_else(|| json_string(item.get("dash_h264")));
+            Some(Movie {
+                id: item.get("id").and_then(value_to_i64)?,
+                name: json_string(item.get("name")),
+                thumbnail_url: json_string(item.get("thumbnail")),
+                video_url,
+            })
+        })
+        .collect()
+}
+
+fn parse_id_description_list(value: Option<&Value>) -> Vec<DictItem> {
+    let Some(Value::Array(items)) = value else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .filter_map(|item| {
+            let id = item.get("id")?.as_i64()?.to_string();
+            let name = item.get("description")?.as_str()?.to_string();
+            Some(DictItem { id, name })
+        })
+        .collect()
+}
+
+fn parse_string_list(value: Option<&Value>) -> Vec<String> {
+    let Some(Value::Array(items)) = value else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .filter_map(|item| item.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReviewFilter {
+    /// Sorted by helpfulness; the only order that honors --day-range.
+    #[default]
+    All,
+    Recent,
+    Updated,
+}
+
+impl ReviewFilter {
+    pub fn as_param(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Recent => "recent",
+            Self::Updated => "updated",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReviewType {
+    #[default]
+    All,
+    Positive,
+    Negative,
+}
+
+impl ReviewType {
+    pub fn as_param(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Positive => "positive",
+            Self::Negative => "negative",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PurchaseType {
+    #[default]
+    All,
+    Steam,
+    NonSteam,
+}
+
+impl PurchaseType {
+    pub fn as_param(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Steam => "steam",
+            Self::NonSteam => "non_steam_purchase",
+        }
+    }
+}
+
+/// Filters for the Store `appreviews` endpoint; everything but the cursor and page size.
+#[derive(Debug, Clone)]
+pub struct ReviewQuery {
+    pub locale: StoreLocale,
+    pub language: String,
+    pub purchase_type: PurchaseType,
+    pub review_type: ReviewType,
+    pub filter: ReviewFilter,
+    pub day_range: Option<u32>,
+}
+
+impl ReviewQuery {
+    /// Key for `review_summary_cache`; the summary depends on every filter, and
+    /// `review_score_desc` on the display language.
+    pub fn cache_key(&self) -> String {
+        format!(
+            "l={}&language={}&purchase_type={}&review_type={}&filter={}&day_range={}",
+            self.locale.lang,
+            self.language.trim().to_lowercase(),
+            self.purchase_type.as_param(),
+            self.review_type.as_param(),
+            self.filter.as_param(),
+            self.day_range.map(|d| d.to_string()).unwrap_or_default()
+        )
+    }
+
+    fn append_pairs(&self, url: &mut Url) {
+        let mut qp = url.query_pairs_mut();
+        qp.append_pair("language", self.language.trim());
+        qp.append_pair("purchase_type", self.purchase_type.as_param());
+        qp.append_pair("review_type", self.review_type.as_param());
+        qp.append_pair("filter", self.filter.as_param());
+        if let Some(days) = self.day_range {
+            qp.append_pair("day_range", &days.to_string());
+        }
+    }
+}
+
+pub struct ReviewsPage {
+    /// Only sent with the first page (`cursor=*`).
+    pub summary: Option<AppReviewSummary>,
+    pub reviews: Vec<AppReview>,
+    /// Null once the Store stops returning new reviews.
+    pub next_cursor: Option<String>,
+}
+
+pub async fn fetch_app_reviews(
+    appid: i64,
+    query: &ReviewQuery,
+    cursor: &str,
+    limit: usize,
+) -> Result<ReviewsPage, AppError> {
+    let mut url = Url::parse(&format!(
+        "https://store.steampowered.com/appreviews/{appid}"
+    ))
+    .map_err(|e| AppError::Internal(e.to_string()))?;
+    {
+        let mut qp = url.query_pairs_mut();
+        qp.append_pair("json", "1");
+        qp.append_pair("cursor", cursor);
+        qp.append_pair("num_per_page", &limit.to_string());
+    }
+    append_locale_pairs(&mut url, &query.locale);
+    query.append_pairs(&mut url);
+
+    let text = reqwest::Client::new()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    parse_reviews_json(&text, cursor)
+}
+
+fn parse_reviews_json(json_text: &str, cursor: &str) -> Result<ReviewsPage, AppError> {
+    let root: Value =
+        serde_json::from_str(json_text).map_err(|e| AppError::UpstreamSchema(e.to_string()))?;
+    if root.get("success").and_then(value_to_i64) != Some(1) {
+        return Err(AppError::UpstreamSchema(
+            "appreviews returned success != 1".to_string(),
+        ));
+    }
+
+    let summary = root.get("query_summary").and_then(|s| {
+        let total_positive = s.get("total_positive").and_then(value_to_i64)?;
+        let total_negative = s.get("total_negative").and_then(value_to_i64)?;
+        let total_reviews = s.get("total_reviews").and_then(value_to_i64)?;
+        let rated = total_positive + total_negative;
+        Some(AppReviewSummary {
+            review_score: s
+                .get("review_score")
+                .and_then(value_to_i64)
+                .unwrap_or_default(),
+            review_score_desc: json_string(s.get("review_score_desc")).unwrap_or_default(),
+            total_positive,
+            total_negative,
+            total_reviews,
+            percent_positive: (rated > 0).then(|| (total_positive * 100 + rated / 2) / rated),
+        })
+    });
+
+    let reviews = root
+        .get("reviews")
+        .and_then(|v| v.as_array())
+        .map(|items| items.iter().filter_map(parse_review).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let next_cursor =
+        json_string(root.get("cursor")).filter(|next| !reviews.is_empty() && next != cursor);
+
+    Ok(ReviewsPage {
+        summary,
+        reviews,
+        next_cursor,
+    })
+}
+
+fn parse_review(item: &Value) -> Option<AppReview> {
+    let flag = |key: &str| item.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+    let number = |key: &str| item.get(key).and_then(value_to_i64).unwrap_or_default();
+    let author = item.get("author");
+    Some(AppReview {
+        recommendation_id: json_string(item.get("recommendationid"))?,
+        author_steamid: json_string(author.and_then(|a| a.get("steamid"))),
+        language: json_string(item.get("language")).unwrap_or_default(),
+        text: item
+            .get("review")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        voted_up: flag("voted_up"),
+        votes_up: number("votes_up"),
+        votes_funny: number("votes_funny"),
+        weighted_vote_score: item
+            .get("weighted_vote_score")
+            .and_then(|v| v.as_f64().or_else(|| v.as_str()?.parse().ok()))
+            .unwrap_or_default(),
+        comment_count: number("comment_count"),
+        steam_purchase: flag("steam_purchase"),
+        received_for_free: flag("received_for_free"),
+        written_during_early_access: flag("written_during_early_access"),
+        created_at: number("timestamp_created"),
+        updated_at: number("timestamp_updated"),
+        playtime_forever_min: author
+            .and_then(|a| a.get("playtime_forever"))
+            .and_then(value_to_i64)
+            .unwrap_or_default(),
+        playtime_at_review_min: author
+            .and_then(|a| a.get("playtime_at_review"))
+            .and_then(value_to_i64),
+    })
+}
+
+/// Fetches full-length news items (`maxlength=0`); truncation is applied on read so
+/// the local cache always holds complete contents.
+pub async fn get_news_for_app(
+    appid: i64,
+    count: usize,
+    feeds: &[String],
+) -> Result<Vec<NewsItem>, AppError> {
+    let mut url = Url::parse("https://api.steampowered.com/ISteamNews/GetNewsForApp/v2/")
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    {
+        let mut qp = url.query_pairs_mut();
+        qp.append_pair("appid", &appid.to_string());
+        qp.append_pair("count", &count.to_string());
+        qp.append_pair("maxlength", "0");
+        if !feeds.is_empty() {
+            qp.append_pair("feeds", &feeds.join(","));
+        }
+        qp.append_pair("format", "json");
+    }
+
+    let response = reqwest::Client::new().get(url).send().await?;
+    if response.status() == reqwest::StatusCode::FORBIDDEN {
+        return Err(AppError::NotFound(format!("no news for appid {appid}")));
+    }
+    let text = response.text().await?;
+    parse_news_json(&text)
+}
+
+fn parse_news_json(json_text: &str) -> Result<Vec<NewsItem>, AppError> {
+    let root: Value =
+        serde_json::from_str(json_text).map_err(|e| AppError::UpstreamSchema(e.to_string()))?;
+    let items = root
+        .get("appnews")
+        .and_then(|n| n.get("newsitems"))
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| AppError::UpstreamSchema("appnews newsitems missing".to_string()))?;
+
+    Ok(items
+        .iter()
+        .filter_map(|item| {
+            Some(NewsItem {
+                gid: json_string(item.get("gid"))?,
+                title: json_string(item.get("title")).unwrap_or_default(),
+                url: json_string(item.get("url")),
+                author: json_string(item.get("author")),
+                date: item.get("date").and_then(value_to_i64).unwrap_or_default(),
+                feedlabel: json_string(item.get("feedlabel")),
+                feedname: json_string(item.get("feedname")),
+                contents: item
+                    .get("contents")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Current concurrent players for several apps, paced like appdetails batches.
+pub async fn fetch_player_counts(appids: &[i64]) -> Vec<(i64, Result<i64, AppError>)> {
+    paced_batch(
+        appids,
+        |appid| async move { get_current_players(appid).await },
+    )
+    .await
+}
+
+async fn get_current_players(appid: i64) -> Result<i64, AppError> {
+    let mut url =
+        Url::parse("https://api.steampowered.com/ISteamUserStats/GetNumberOfCurrentPlayers/v1/")
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+    url.query_pairs_mut()
+        .append_pair("appid", &appid.to_string())
+        .append_pair("format", "json");
+
+    let response = reqwest::Client::new().get(url).send().await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(AppError::NotFound(format!("appid {appid} not found")));
+    }
+    let json: Value = response.json().await?;
+    parse_player_count(appid, &json)
+}
+
+/// `result` is 1 on success; other values (42 for unknown apps) carry no count.
+fn parse_player_count(appid: i64, json: &Value) -> Result<i64, AppError> {
+    let response = json
+        .get("response")
+        .ok_or_else(|| AppError::UpstreamSchema("player count response missing".to_string()))?;
+    if response.get("result").and_then(value_to_i64) != Some(1) {
+        return Err(AppError::NotFound(format!(
+            "no player count for appid {appid}"
+        )));
+    }
+    response
+        .get("player_count")
+        .and_then(value_to_i64)
+        .ok_or_else(|| AppError::UpstreamSchema("player_count missing".to_string()))
+}
+
+/// Game name and achievement definitions from `GetSchemaForGame` (needs an API key).
+pub async fn get_achievement_schema(
+    api_key: &str,
+    appid: i64,
+    locale: &StoreLocale,
+) -> Result<(Option<String>, Vec<Achievement>), AppError> {
+    let mut url = Url::parse("https://api.steampowered.com/ISteamUserStats/GetSchemaForGame/v2/")
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    url.query_pairs_mut()
+        .append_pair("key", api_key)
+        .append_pair("appid", &appid.to_string())
+        .append_pair("l", &locale.lang)
+        .append_pair("format", "json");
+
+    let response = reqwest::Client::new().get(url).send().await?;
+    match response.status() {
+        reqwest::StatusCode::FORBIDDEN | reqwest::StatusCode::UNAUTHORIZED => {
+            return Err(AppError::Unauthorized(
+                "STEAM_API_KEY was rejected by GetSchemaForGame".to_string(),
+            ));
+        }
+        reqwest::StatusCode::BAD_REQUEST | reqwest::StatusCode::NOT_FOUND => {
+            return Err(AppError::NotFound(format!("appid {appid} not found")));
+        }
+        _ => {}
+    }
+    let json: Value = response.json().await?;
+    Ok(parse_achievement_schema(&json))
+}
+
+fn parse_achievement_schema(json: &Value) -> (Option<String>, Vec<Achievement>) {
+    let game = json.get("game");
+    let name = json_string(game.and_then(|g| g.get("gameName")));
+    let items = game
+        .and_then(|g| g.get("availableGameStats"))
+        .and_then(|s| s.get("achievements"))
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    Some(Achievement {
+                        name: json_string(item.get("name"))?,
+                        display_name: json_string(item.get("displayName")),
+                        description: json_string(item.get("description")),
+                        hidden: item.get("hidden").and_then(value_to_i64) == Some(1),
+                        icon: json_string(item.get("icon")),
+                        icon_gray: json_string(item.get("icongray")),
+                        percent: None,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    (name, items)
+}
+
+/// Global unlock percentages keyed by achievement API name. Apps without
+/// achievements answer 403, which is reported as an empty map.
+pub async fn get_global_achievement_percentages(
+    appid: i64,
+) -> Result<HashMap<String, f64>, AppError> {
+    let mut url = Url::parse(
+        "https://api.steampowered.com/ISteamUserStats/GetGlobalAchievementPercentagesForApp/v2/",
+    )
+    .map_err(|e| AppError::Internal(e.to_string()))?;
+    url.query_pairs_mut()
+        .append_pair("gameid", &appid.to_string())
+        .append_pair("format", "json");
+
+    let response = reqwest::Client::new().get(url).send().await?;
+    if response.status() == reqwest::StatusCode::FORBIDDEN {
+        return Ok(HashMap::new());
+    }
+    let json: Value = response.json().await?;
+    parse_achievement_percentages(&json)
+}
+
+fn parse_achievement_percentages(json: &Value) -> Result<HashMap<String, f64>, AppError> {
+    let items = json
+        .get("achievementpercentages")
+        .and_then(|p| p.get("achievements"))
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| AppError::UpstreamSchema("achievementpercentages missing".to_string()))?;
+    Ok(items
+        .iter()
+        .filter_map(|item| {
+            let name = json_string(item.get("name"))?;
+            let percent = item.get("percent").and_then(|v| {
+                v.as_f64()
+                    .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+            })?;
+            Some((name, percent))
+        })
+        .collect())
+}
+
+/// Joins schema and percentages, rarest first. Achievements without a percentage
+/// sort last; percentages without a schema entry keep only their API name.
+pub fn merge_achievements(
+    schema: Vec<Achievement>,
+    mut percentages: HashMap<String, f64>,
+) -> Vec<Achievement> {
+    let mut out = schema
+        .into_iter()
+        .map(|mut item| {
+            item.percent = percentages.remove(&item.name);
+            item
+        })
+        .collect::<Vec<_>>();
+    out.extend(percentages.into_iter().map(|(name, percent)| Achievement {
+        name,
+        display_name: None,
+        description: None,
+        hidden: false,
+        icon: None,
+        icon_gray: None,
+        percent: Some(percent),
+    }));
+    out.sort_by(|a, b| match (a.percent, b.percent) {
+        (Some(x), Some(y)) => x.total_cmp(&y).then_with(|| a.name.cmp(&b.name)),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.name.cmp(&b.name),
+    });
+    out
+}
+
+/// Cookies that get the store page past the age gate for mature titles.
+const AGE_GATE_COOKIES: &str = "birthtime=0; lastagecheckage=1-0-1900; wants_mature_content=1";
+
+/// Community tags from the app's store page, most-voted first.
+pub async fn fetch_app_tags(appid: i64, locale: &StoreLocale) -> Result<Vec<AppTag>, AppError> {
+    parse_app_tags(&fetch_store_page(appid, locale).await?)
+}
+
+/// Franchise named on the app's store page, if it belongs to one.
+pub async fn fetch_app_franchise(
+    appid: i64,
+    locale: &StoreLocale,
+) -> Result<Option<String>, AppError> {
+    Ok(parse_app_franchise(&fetch_store_page(appid, locale).await?))
+}
+
+async fn fetch_store_page(appid: i64, locale: &StoreLocale) -> Result<String, AppError> {
+    let mut url = Url::parse(&format!("https://store.steampowered.com/app/{appid}/"))
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    append_locale_pairs(&mut url, locale);
+
+    let response = reqwest::Client::new()
+        .get(url)
+        .header(reqwest::header::COOKIE, AGE_GATE_COOKIES)
+        .send()
+        .await?
+        .error_for_status()?;
+    // Unknown apps redirect to the Store front page.
+    if !response.url().path().starts_with(&format!("/app/{appid}")) {
+        return Err(AppError::NotFound(format!("appid {appid} not found")));
+    }
+    Ok(response.text().await?)
+}
+
+/// Per-language interface/full audio/subtitles support from the store page table.
+pub async fn fetch_app_languages(
+    appid: i64,
+    locale: &StoreLocale,
+) -> Result<Vec<LanguageSupport>, AppError> {
+    parse_language_table(&fetch_store_page(appid, locale).await?)
+}
+
+/// Fetches store-page language tables for several apps, paced like appdetails batches.
+pub async fn fetch_app_languages_batch(
+    appids: &[i64],
+    locale: &StoreLocale,
+) -> Vec<(i64, Result<Vec<LanguageSupport>, AppError>)> {
+    paced_batch(appids, |appid| {
+        let locale = locale.clone();
+        async move { fetch_app_languages(appid, &locale).await }
+    })
+    .await
+}
+
+/// Fetches store-page tags for several apps, paced like appdetails batches.
+pub async fn fetch_app_tags_batch(
+    appids: &[i64],
+    locale: &StoreLocale,
+) -> Vec<(i64, Result<Vec<AppTag>, AppError>)> {
+    paced_batch(appids, |appid| {
+        let locale = locale.clone();
+        async move { fetch_app_tags(appid, &locale).await }
+    })
+    .await
+}
+
+/// The franchise link sits in the details block under a localized label, so only
+/// the `/franchise/` link target is relied on.
+fn parse_app_franchise(html_text: &str) -> Option<String> {
+    let document = Html::parse_document(html_text);
+    let link = selector(r#"a[href*="/franchise/"]"#).ok()?;
+    document
+        .select(&link)
+        .map(element_text)
+        .find(|name| !name.is_empty())
+}
+
+/// Reads `table.game_language_options`: a name cell, then interface, full audio and
+/// subtitles check cells, in that order. Rows past the first few are only hidden,
+/// so they are kept; rows marked `unsupported` are dropped.
+fn parse_language_table(html_text: &str) -> Result<Vec<LanguageSupport>, AppError> {
+    let document = Html::parse_document(html_text);
+    let table = selector("table.game_language_options")?;
+    let rows = selector("tr")?;
+    let cells = selector("td")?;
+    let table = document.select(&table).next().ok_or_else(|| {
+        AppError::UpstreamSchema("language table not found in store page".to_string())
+    })?;
+
+    let mut out = Vec::new();
+    for row in table.select(&rows) {
+        if row.value().classes().any(|class| class == "unsupported") {
+            continue;
+        }
+        let row_cells = row.select(&cells).collect::<Vec<_>>();
+        let [name, interface, full_audio, subtitles] = row_cells.as_slice() else {
+            continue;
+        };
+        let language = element_text(*name);
+        if language.is_empty() {
+            continue;
+        }
+        let checked = |cell: &ElementRef<'_>| !element_text(*cell).is_empty();
+        out.push(LanguageSupport {
+            language,
+            interface: Some(checked(interface)),
+            full_audio: checked(full_audio),
+            subtitles: Some(checked(subtitles)),
+        });
+    }
+    Ok(out)
+}
+
+/// Reads the tag list passed to `InitAppTagModal(appid, [...], ...)` on the store page.
+fn parse_app_tags(html_text: &str) -> Result<Vec<AppTag>, AppError> {
+    let raw_tags = APP_TAG_MODAL_RE
+        .captures(html_text)
+        .and_then(|caps| caps.get(1))
+        .ok_or_else(|| AppError::UpstreamSchema("tag block not found in store page".to_string()))?
+        .as_str();
+
+    let parsed: Vec<Value> = serde_json::from_str(raw_tags)
+        .map_err(|e| AppError::UpstreamSchema(format!("tag parse failed: {e}")))?;
+
+    Ok(parsed
+        .iter()
+        .filter_map(|tag| {
+            Some(AppTag {
+                tagid: tag.get("tagid").and_then(value_to_i64)?,
+                name: json_string(tag.get("name"))?,
+                votes: tag.get("count").and_then(value_to_i64).unwrap_or_default(),
+                unknown: false,
+            })
+        })
+        .collect())
+}
+
+/// Rows per `GetAppList` page; the Web API caps `max_results` at 50000.
+const CATALOG_PAGE_SIZE: usize = 50_000;
+
+/// Pause between `GetAppList` pages during `catalog sync`.
+pub const CATALOG_PAGE_DELAY: Duration = Duration::from_millis(1_000);
+
+pub struct AppListPage {
+    pub apps: Vec<CatalogApp>,
+    pub have_more: bool,
+    /// Pass back as `last_appid` to continue after this page.
+    pub last_appid: Option<i64>,
+}
+
+/// Which app types to request besides games.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CatalogInclude {
+    pub dlc: bool,
+    pub software: bool,
+}
+
+pub async fn get_app_list_page(
+    api_key: &str,
+    if_modified_since: Option<i64>,
+    last_appid: Option<i64>,
+    include: CatalogInclude,
+) -> Result<AppListPage, AppError> {
+    let mut url = Url::parse("https://api.steampowered.com/IStoreService/GetAppList/v1/")
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    {
+        let mut qp = url.query_pairs_mut();
+        qp.append_pair("key", api_key);
+        qp.append_pair("max_results", &CATALOG_PAGE_SIZE.to_string());
+        qp.append_pair("include_games", "1");
+        qp.append_pair("include_dlc", if include.dlc { "1" } else { "0" });
+        qp.append_pair("include_software", if include.software { "1" } else { "0" });
+        if let Some(since) = if_modified_since {
+            qp.append_pair("if_modified_since", &since.to_string());
+        }
+        if let Some(last) = last_appid {
+            qp.append_pair("last_appid", &last.to_string());
+        }
+    }
+
+    let response = reqwest::Client::new().get(url).send().await?;
+    if matches!(
+        response.status(),
+        reqwest::StatusCode::FORBIDDEN | reqwest::StatusCode::UNAUTHORIZED
+    ) {
+        return Err(AppError::Unauthorized(
+            "STEAM_API_KEY was rejected by GetAppList".to_string(),
+        ));
+    }
+    let json: Value = response.json().await?;
+    parse_app_list(&json)
+}
+
+/// An up-to-date catalog answers with an empty `response` object.
+fn parse_app_list(json: &Value) -> Result<AppListPage, AppError> {
+    let response = json
+        .get("response")
+        .ok_or_else(|| AppError::UpstreamSchema("app list response missing".to_string()))?;
+    let apps = response
+        .get("apps")
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    Some(CatalogApp {
+                        appid: item.get("appid").and_then(value_to_i64)?,
+                        name: json_string(item.get("name"))?,
+                        last_modified: item.get("last_modified").and_then(value_to_i64),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(AppListPage {
+        apps,
+        have_more: response
+            .get("have_more_results")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        last_appid: response.get("last_appid").and_then(value_to_i64),
+    })
 }
 
 pub async fn resolve_vanity(api_key: &str, vanity: &str) -> Result<String, AppError> {
@@ -289,3 +2145,427 @@ pub async fn get_owned_games(api_key: &str, steamid: &str) -> Result<Vec<OwnedGa
 
     Ok(out)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STARDEW: &str = include_str!("../tests/fixtures/appdetails_413150.json");
+    const DOTA: &str = include_str!("../tests/fixtures/appdetails_570.json");
+    const COMING_SOON: &str = include_str!("../tests/fixtures/appdetails_coming_soon.json");
+    const REVIEWS: &str = include_str!("../tests/fixtures/appreviews_413150.json");
+    const NEWS: &str = include_str!("../tests/fixtures/news_413150.json");
+    const STORE_PAGE: &str = include_str!("../tests/fixtures/store_page_413150.html");
+    const PACKAGE: &str = include_str!("../tests/fixtures/packagedetails_54029.json");
+    const SEARCH_ROWS: &str = include_str!("../tests/fixtures/search_rows.html");
+    const STARDEW_GERMAN: &str = include_str!("../tests/fixtures/appdetails_german.json");
+    const SEARCH_EMPTY: &str = include_str!("../tests/fixtures/search_empty.json");
+    const SEARCH_NO_RESULTS: &str = include_str!("../tests/fixtures/search_no_results.json");
+    const SEARCH_CHANGED: &str = include_str!("../tests/fixtures/search_changed_markup.json");
+
+    #[test]
+    fn language_table_is_read_from_store_page() {
+        let langs = parse_language_table(STORE_PAGE).unwrap();
+
+        let names = langs
+            .iter()
+            .map(|l| l.language.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["English", "German", "Japanese", "Hungarian"]);
+        let english = &langs[0];
+        assert_eq!(english.interface, Some(true));
+        assert!(!english.full_audio);
+        assert_eq!(english.subtitles, Some(true));
+        let japanese = &langs[2];
+        assert_eq!(japanese.interface, Some(true));
+        assert_eq!(japanese.subtitles, Some(false));
+
+        assert!(matches!(
+            parse_language_table("<html><body></body></html>"),
+            Err(AppError::UpstreamSchema(_))
+        ));
+    }
+
+    #[test]
+    fn franchise_link_is_read_from_store_page() {
+        assert_eq!(parse_app_franchise(STORE_PAGE), None);
+        let page = r#"<div class="dev_row"><b>Franchise:</b>
+            <a href="https://store.steampowered.com/franchise/TeamCherry?snr=1_5_9__408">Hollow Knight</a><br></div>"#;
+        assert_eq!(parse_app_franchise(page).as_deref(), Some("Hollow Knight"));
+    }
+
+    #[test]
+    fn empty_search_pages_are_not_schema_errors() {
+        let empty = parse_search_json(SEARCH_EMPTY, 0).unwrap();
+        assert!(empty.items.is_empty());
+        assert_eq!(empty.total, Some(0));
+
+        let marked = parse_search_json(SEARCH_NO_RESULTS, 0).unwrap();
+        assert!(marked.items.is_empty());
+        assert_eq!(marked.total, None);
+        assert!(
+            NO_RESULTS_MARKERS
+                .iter()
+                .any(|marker| SEARCH_NO_RESULTS.contains(marker))
+        );
+    }
+
+    #[test]
+    fn changed_search_markup_is_a_schema_error() {
+        assert!(matches!(
+            parse_search_json(SEARCH_CHANGED, 0),
+            Err(AppError::UpstreamSchema(_))
+        ));
+        // Throttled and 5xx replies are rejected by status before parsing; only a
+        // 2xx body that is not the JSON response is a schema error.
+        assert!(matches!(
+            parse_search_json("<html><body>Steam Store</body></html>", 0),
+            Err(AppError::UpstreamSchema(_))
+        ));
+    }
+
+    #[test]
+    fn non_english_appdetails_keep_raw_date_and_localized_languages() {
+        let app = normalize_appdetails(413150, STARDEW_GERMAN).unwrap();
+        let date = app.release_date.unwrap();
+        assert_eq!(date.raw.as_deref(), Some("26. Feb. 2016"));
+        assert_eq!(date.iso_date, None);
+
+        let names = app
+            .supported_languages
+            .iter()
+            .map(|lang| lang.language.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Englisch", "Deutsch", "Japanisch"]);
+        assert!(app.supported_languages[0].full_audio);
+        assert!(!language_matches("Japanisch", "japanese"));
+    }
+
+    #[test]
+    fn any_tag_groups_merge_by_best_position() {
+        let rows = parse_search_rows(SEARCH_ROWS).unwrap();
+        let pick = |ids: &[i64]| {
+            ids.iter()
+                .map(|id| rows.iter().find(|row| row.id == *id).unwrap().clone())
+                .collect::<Vec<_>>()
+        };
+        let run = |items, facets| SearchRun {
+            items,
+            facets,
+            pages: 1,
+            has_more: false,
+            total: Some(100),
+        };
+        let runs = vec![
+            (0, run(pick(&[1145360, 570, 2000010]), Some(Vec::new()))),
+            (1, run(pick(&[367520, 2000010, 1145360]), None)),
+        ];
+
+        let merged = merge_group_runs(runs, 3);
+
+        let ids = merged.items.iter().map(|item| item.id).collect::<Vec<_>>();
+        assert_eq!(ids, [1145360, 367520, 570]);
+        let groups = merged
+            .items
+            .iter()
+            .map(|item| item.matched_groups.clone().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(groups, [vec![0, 1], vec![1], vec![0]]);
+        // The fourth unique row (2000010) was cut by the limit.
+        assert!(merged.has_more);
+        assert_eq!(merged.pages, 2);
+        assert_eq!(merged.total, None);
+        assert!(merged.facets.is_some_and(|facets| facets.is_empty()));
+    }
+
+    #[test]
+    fn search_rows_read_prices_reviews_and_currency() {
+        let items = parse_search_rows(SEARCH_ROWS).unwrap();
+        assert_eq!(items.len(), 4);
+
+        let hades = &items[0];
+        assert_eq!(hades.appid, Some(1145360));
+        assert_eq!(hades.price.as_deref(), Some("$12.49"));
+        assert_eq!(hades.final_price_cents, Some(1249));
+        assert_eq!(hades.original_price_cents, Some(2499));
+        assert_eq!(hades.discount_percent, Some(50));
+        assert_eq!(hades.currency.as_deref(), Some("USD"));
+        let review = hades.review.as_ref().unwrap();
+        assert_eq!(review.summary, "Overwhelmingly Positive");
+        assert_eq!(review.percent_positive, Some(98));
+        assert_eq!(review.total_reviews, Some(265_817));
+        assert!(hades.platforms.windows && hades.platforms.mac && !hades.platforms.linux);
+
+        let dota = &items[1];
+        assert_eq!(dota.price.as_deref(), Some("Free To Play"));
+        assert_eq!(dota.final_price_cents, Some(0));
+        assert_eq!(dota.original_price_cents, Some(0));
+        assert_eq!(dota.discount_percent, None);
+        assert_eq!(dota.currency, None);
+
+        let unreviewed = &items[2];
+        assert!(unreviewed.review.is_none());
+        assert_eq!(unreviewed.final_price_cents, Some(1999));
+        assert_eq!(unreviewed.currency.as_deref(), Some("EUR"));
+        assert_eq!(unreviewed.release_date.as_deref(), Some("3 Mar, 2025"));
+
+        let hollow = &items[3];
+        assert_eq!(hollow.price.as_deref(), Some("7,39€"));
+        assert_eq!(hollow.final_price_cents, Some(739));
+        assert_eq!(hollow.original_price_cents, Some(1479));
+        assert_eq!(hollow.currency.as_deref(), Some("EUR"));
+        assert_eq!(hollow.review.as_ref().unwrap().total_reviews, Some(400_123));
+    }
+
+    #[test]
+    fn paid_app_has_typed_price_platforms_and_date() {
+        let app = normalize_appdetails(413150, STARDEW).unwrap();
+
+        let price = app.price_overview.unwrap();
+        assert_eq!(price.currency.as_deref(), Some("USD"));
+        assert_eq!(price.initial_cents, 1499);
+        assert_eq!(price.final_cents, 1049);
+        assert_eq!(price.discount_percent, 30);
+
+        assert!(app.platforms.windows && app.platforms.mac && app.platforms.linux);
+
+        let date = app.release_date.unwrap();
+        assert_eq!(date.raw.as_deref(), Some("26 Feb, 2016"));
+        assert_eq!(date.iso_date.as_deref(), Some("2016-02-26"));
+        assert!(!date.coming_soon);
+    }
+
+    #[test]
+    fn free_app_has_no_price_and_month_first_date() {
+        let app = normalize_appdetails(570, DOTA).unwrap();
+
+        assert!(app.is_free);
+        assert!(app.price_overview.is_none());
+        assert!(app.platforms.windows && !app.platforms.mac && app.platforms.linux);
+        assert_eq!(app.required_age, 0);
+
+        let date = app.release_date.unwrap();
+        assert_eq!(date.iso_date.as_deref(), Some("2013-07-09"));
+    }
+
+    #[test]
+    fn coming_soon_app_keeps_raw_date_without_iso() {
+        let app = normalize_appdetails(2000010, COMING_SOON).unwrap();
+
+        let date = app.release_date.unwrap();
+        assert_eq!(date.raw.as_deref(), Some("Q3 2026"));
+        assert!(date.iso_date.is_none());
+        assert!(date.coming_soon);
+        assert!(app.price_overview.is_none());
+        assert!(app.publishers.is_empty());
+        assert_eq!(app.content_descriptors.ids, vec![1, 5]);
+    }
+
+    #[test]
+    fn typed_fields_serialize_as_objects() {
+        let app = normalize_appdetails(413150, STARDEW).unwrap();
+        let json = serde_json::to_value(&app).unwrap();
+
+        assert_eq!(json["platforms"]["linux"], Value::Bool(true));
+        assert_eq!(json["price_overview"]["final_cents"], Value::from(1049));
+        assert_eq!(json["release_date"]["iso_date"], Value::from("2016-02-26"));
+    }
+
+    #[test]
+    fn supported_languages_mark_full_audio() {
+        let app = normalize_appdetails(570, DOTA).unwrap();
+        let langs = &app.supported_languages;
+
+        assert_eq!(langs.len(), 29);
+        assert_eq!(langs[0].language, "English");
+        assert!(langs[0].full_audio);
+        assert!(langs[0].interface.is_none() && langs[0].subtitles.is_none());
+        let japanese = langs.iter().find(|l| l.language == "Japanese").unwrap();
+        assert!(!japanese.full_audio);
+        let korean = langs.iter().find(|l| l.language == "Korean").unwrap();
+        assert!(korean.full_audio);
+        assert!(!langs.iter().any(|l| l.language.contains("audio support")));
+
+        assert!(language_matches("Spanish - Spain", "spanish"));
+        assert!(language_matches("Simplified Chinese", "simplified chinese"));
+        assert!(!language_matches("Simplified Chinese", "chinese"));
+
+        // Store codes accepted by `search --language` work here too.
+        assert!(language_matches("Simplified Chinese", "schinese"));
+        assert!(!language_matches("Traditional Chinese", "schinese"));
+        assert!(language_matches("Korean", "koreana"));
+        assert!(language_matches("Spanish - Latin America", "latam"));
+        assert!(language_matches("Portuguese - Brazil", "brazilian"));
+        assert!(!language_matches("Portuguese - Portugal", "brazilian"));
+        assert!(language_matches("Japanese", "japanese"));
+    }
+
+    #[test]
+    fn reviews_page_has_summary_and_cursor() {
+        let page = parse_reviews_json(REVIEWS, "*").unwrap();
+
+        let summary = page.summary.unwrap();
+        assert_eq!(summary.review_score_desc, "Overwhelmingly Positive");
+        assert_eq!(summary.total_reviews, 641337);
+        assert_eq!(summary.percent_positive, Some(99));
+
+        assert_eq!(page.reviews.len(), 2);
+        let first = &page.reviews[0];
+        assert!(first.voted_up && first.steam_purchase);
+        assert_eq!(first.playtime_at_review_min, Some(4410));
+        assert!((first.weighted_vote_score - 0.8123).abs() < 1e-3);
+        assert!(page.reviews[1].received_for_free);
+        assert!(page.reviews[1].playtime_at_review_min.is_none());
+
+        assert_eq!(page.next_cursor.as_deref(), Some("AoJ4sJbR/48CezqQwQY="));
+        let last = parse_reviews_json(REVIEWS, "AoJ4sJbR/48CezqQwQY=").unwrap();
+        assert!(last.next_cursor.is_none());
+    }
+
+    #[test]
+    fn news_items_parse_with_feed_metadata() {
+        let items = parse_news_json(NEWS).unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].gid, "5794731234567890123");
+        assert_eq!(
+            items[0].feedname.as_deref(),
+            Some("steam_community_announcements")
+        );
+        assert_eq!(items[0].date, 1727800000);
+        assert!(items[1].author.is_none());
+        assert!(parse_news_json("{}").is_err());
+    }
+
+    #[test]
+    fn player_count_requires_success_result() {
+        let ok = serde_json::json!({"response": {"player_count": 41234, "result": 1}});
+        assert_eq!(parse_player_count(413150, &ok).unwrap(), 41234);
+
+        let missing = serde_json::json!({"response": {"result": 42}});
+        assert!(matches!(
+            parse_player_count(1, &missing),
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn achievements_merge_sorted_by_rarity() {
+        let schema = serde_json::json!({"game": {"gameName": "Portal 2", "availableGameStats": {"achievements": [
+            {"name": "ACH.SURVIVE_CONTAINER_RIDE", "displayName": "Wake Up Call", "hidden": 0, "icon": "a.jpg", "icongray": "a_gray.jpg"},
+            {"name": "ACH.WAKE_UP", "displayName": "You Monster", "description": "Reunite with GLaDOS", "hidden": 1},
+            {"name": "ACH.NO_STATS", "displayName": "Untracked", "hidden": 0}
+        ]}}});
+        let percentages = serde_json::json!({"achievementpercentages": {"achievements": [
+            {"name": "ACH.SURVIVE_CONTAINER_RIDE", "percent": "93.5"},
+            {"name": "ACH.WAKE_UP", "percent": 71.2},
+            {"name": "ACH.REMOVED", "percent": 0.4}
+        ]}});
+
+        let (game_name, schema) = parse_achievement_schema(&schema);
+        assert_eq!(game_name.as_deref(), Some("Portal 2"));
+        let merged =
+            merge_achievements(schema, parse_achievement_percentages(&percentages).unwrap());
+
+        let names = merged.iter().map(|a| a.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "ACH.REMOVED",
+                "ACH.WAKE_UP",
+                "ACH.SURVIVE_CONTAINER_RIDE",
+                "ACH.NO_STATS"
+            ]
+        );
+        assert!(merged[1].hidden);
+        assert_eq!(merged[2].percent, Some(93.5));
+        assert!(merged[3].percent.is_none());
+    }
+
+    #[test]
+    fn store_page_tags_parse_in_page_order() {
+        let tags = parse_app_tags(STORE_PAGE).unwrap();
+
+        assert_eq!(tags.len(), 4);
+        assert_eq!(tags[0].tagid, 597);
+        assert_eq!(tags[0].name, "Farming Sim");
+        assert_eq!(tags[0].votes, 12745);
+        assert_eq!(tags[3].name, "Pixel Graphics");
+        assert!(parse_app_tags("<html></html>").is_err());
+    }
+
+    #[test]
+    fn package_details_list_apps_and_price() {
+        let package = normalize_packagedetails(54029, PACKAGE).unwrap();
+
+        assert_eq!(package.name, "Portal Bundle");
+        let appids = package.apps.iter().map(|a| a.appid).collect::<Vec<_>>();
+        assert_eq!(appids, vec![400, 620]);
+        let price = package.price.unwrap();
+        assert_eq!(price.final_cents, 1499);
+        assert_eq!(price.discount_percent, 25);
+        assert_eq!(package.individual_price_cents, Some(1998));
+        assert!(package.platforms.linux);
+        assert_eq!(
+            package.release_date.unwrap().iso_date.as_deref(),
+            Some("2011-04-18")
+        );
+
+        let missing = r#"{"1":{"success":false}}"#;
+        assert!(matches!(
+            normalize_packagedetails(1, missing),
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn app_list_pages_carry_continuation() {
+        let page = serde_json::json!({"response": {"apps": [
+            {"appid": 367520, "name": "Hollow Knight", "last_modified": 1727000000, "price_change_number": 1},
+            {"appid": 1030300, "name": "Hollow Knight: Silksong", "last_modified": 1757000000},
+            {"appid": 1, "name": ""}
+        ], "have_more_results": true, "last_appid": 1030300}});
+        let parsed = parse_app_list(&page).unwrap();
+        assert_eq!(parsed.apps.len(), 2);
+        assert!(parsed.have_more);
+        assert_eq!(parsed.last_appid, Some(1030300));
+
+        let empty = parse_app_list(&serde_json::json!({"response": {}})).unwrap();
+        assert!(empty.apps.is_empty() && !empty.have_more);
+    }
+
+    #[test]
+    fn english_dates_parse_in_both_layouts() {
+        assert_eq!(
+            english_date_to_iso("7 Aug, 2018").as_deref(),
+            Some("2018-08-07")
+        );
+        assert_eq!(
+            english_date_to_iso("Aug 7, 2018").as_deref(),
+            Some("2018-08-07")
+        );
+        assert_eq!(
+            english_date_to_iso("September 12, 2024").as_deref(),
+            Some("2024-09-12")
+        );
+        assert_eq!(english_date_to_iso("Aug 2018"), None);
+        assert_eq!(english_date_to_iso("Coming soon"), None);
+        assert_eq!(english_date_to_iso("7 août 2018"), None);
+    }
+
+    #[test]
+    fn impossible_dates_have_no_iso_form() {
+        assert_eq!(english_date_to_iso("31 Feb, 2024"), None);
+        assert_eq!(english_date_to_iso("29 Feb, 2023"), None);
+        assert_eq!(english_date_to_iso("31 Apr, 2024"), None);
+        assert_eq!(english_date_to_iso("29 Feb, 2100"), None);
+        assert_eq!(
+            english_date_to_iso("29 Feb, 2024").as_deref(),
+            Some("2024-02-29")
+        );
+        assert_eq!(
+            english_date_to_iso("Feb 29, 2000").as_deref(),
+            Some("2000-02-29")
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use std::fs;
-use std::path::PathBuf;
 
 use rusqlite::{Connection, params};
 
 use crate::error::AppError;
-use crate::models::{DictFindItem, DictItem};
+use crate::models::{
+    AppTag, CatalogApp, DictFindItem, DictItem, NewsItem, PlayerHistory, StoreLocale,
+};
 
 const EMBED_SEED_DB: &[u8] = include_bytes!("../assets/steam.db");
 
@@ -13,6 +14,8 @@ pub enum DictKind {
     Tags,
     Genres,
     Categories,
+    /// The app catalog filled by `catalog sync`.
+    Apps,
 }
 
 impl DictKind {
@@ -21,6 +24,7 @@ impl DictKind {
             Self::Tags => "tags",
             Self::Genres => "genres",
             Self::Categories => "categories",
+            Self::Apps => "apps",
         }
     }
 
@@ -29,6 +33,7 @@ impl DictKind {
             Self::Tags => "tags_fts",
             Self::Genres => "genres_fts",
             Self::Categories => "categories_fts",
+            Self::Apps => "apps_fts",
         }
     }
 }
@@ -51,14 +56,25 @@ impl LocalStore {
             fs::write(&db_path, EMBED_SEED_DB).map_err(|e| AppError::Internal(e.to_string()))?;
         }
 
-        let conn = Connection::open(PathBuf::from(db_path))?;
+        let conn = Connection::open(db_path)?;
         let store = Self { conn };
         store.init_schema()?;
         store.ensure_seeded()?;
         Ok(store)
     }
 
+    /// Empty, unseeded store for tests.
+    #[cfg(test)]
+    pub fn open_in_memory() -> Result<Self, AppError> {
+        let store = Self {
+            conn: Connection::open_in_memory()?,
+        };
+        store.init_schema()?;
+        Ok(store)
+    }
+
     fn init_schema(&self) -> Result<(), AppError> {
+        self.migrate_app_cache()?;
         self.conn.execute_batch(
             "
             CREATE TABLE IF NOT EXISTS tags(id INTEGER PRIMARY KEY, name TEXT NOT NULL);
@@ -69,16 +85,131 @@ impl LocalStore {
             CREATE VIRTUAL TABLE IF NOT EXISTS genres_fts USING fts5(id UNINDEXED, name);
             CREATE VIRTUAL TABLE IF NOT EXISTS categories_fts USING fts5(id UNINDEXED, name);
 
+            CREATE TABLE IF NOT EXISTS apps(
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                last_modified INTEGER
+            );
+            CREATE INDEX IF NOT EXISTS apps_name_lower ON apps(LOWER(name));
+            CREATE VIRTUAL TABLE IF NOT EXISTS apps_fts USING fts5(id UNINDEXED, name);
+
+            CREATE TABLE IF NOT EXISTS sync_state(
+                name TEXT PRIMARY KEY,
+                value INTEGER NOT NULL
+            );
+
             CREATE TABLE IF NOT EXISTS app_cache(
-                appid INTEGER PRIMARY KEY,
+                appid INTEGER NOT NULL,
+                cc TEXT NOT NULL,
+                lang TEXT NOT NULL,
+                payload_json TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(appid, cc, lang)
+            );
+
+            CREATE TABLE IF NOT EXISTS package_cache(
+                subid INTEGER NOT NULL,
+                cc TEXT NOT NULL,
+                lang TEXT NOT NULL,
+                payload_json TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(subid, cc, lang)
+            );
+
+            CREATE TABLE IF NOT EXISTS search_cache(
+                query_key TEXT PRIMARY KEY,
                 payload_json TEXT NOT NULL,
                 fetched_at INTEGER NOT NULL
             );
+
+            CREATE TABLE IF NOT EXISTS app_news(
+                appid INTEGER NOT NULL,
+                gid TEXT NOT NULL,
+                title TEXT NOT NULL,
+                url TEXT,
+                author TEXT,
+                date INTEGER NOT NULL,
+                feedlabel TEXT,
+                feedname TEXT,
+                contents TEXT NOT NULL,
+                PRIMARY KEY(appid, gid)
+            );
+
+            CREATE TABLE IF NOT EXISTS app_news_fetch(
+                appid INTEGER NOT NULL,
+                query_key TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(appid, query_key)
+            );
+
+            CREATE TABLE IF NOT EXISTS app_tags(
+                appid INTEGER NOT NULL,
+                lang TEXT NOT NULL,
+                tagid INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                votes INTEGER NOT NULL,
+                position INTEGER NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(appid, lang, tagid)
+            );
+
+            CREATE TABLE IF NOT EXISTS app_tags_fetch(
+                appid INTEGER NOT NULL,
+                lang TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(appid, lang)
+            );
+
+            CREATE TABLE IF NOT EXISTS app_franchise(
+                appid INTEGER NOT NULL,
+                lang TEXT NOT NULL,
+                name TEXT,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(appid, lang)
+            );
+
+            CREATE TABLE IF NOT EXISTS app_languages(
+                appid INTEGER NOT NULL,
+                lang TEXT NOT NULL,
+                payload_json TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(appid, lang)
+            );
+
+            CREATE TABLE IF NOT EXISTS player_samples(
+                appid INTEGER NOT NULL,
+                sampled_at INTEGER NOT NULL,
+                player_count INTEGER NOT NULL,
+                PRIMARY KEY(appid, sampled_at)
+            );
+
+            CREATE TABLE IF NOT EXISTS review_summary_cache(
+                appid INTEGER NOT NULL,
+                query_key TEXT NOT NULL,
+                payload_json TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY(appid, query_key)
+            );
             ",
         )?;
         Ok(())
     }
 
+    /// Older databases keyed `app_cache` by appid alone. It only holds cached
+    /// payloads, so it is dropped and recreated with the region-aware key.
+    fn migrate_app_cache(&self) -> Result<(), AppError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name FROM pragma_table_info('app_cache')")?;
+        let columns = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        if !columns.is_empty() && !columns.iter().any(|c| c == "cc") {
+            self.conn.execute_batch("DROP TABLE app_cache;")?;
+        }
+        Ok(())
+    }
+
     pub fn ensure_seeded(&self) -> Result<(), AppError> {
         let count: i64 = self
             .conn
@@ -232,15 +363,109 @@ impl LocalStore {
         Ok((out, total))
     }
 
+    pub fn get_dict_item(&self, kind: DictKind, id: &str) -> Result<Option<DictItem>, AppError> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT CAST(id AS TEXT), name FROM {} WHERE CAST(id AS TEXT) = ?",
+            kind.table()
+        ))?;
+        let mut rows = stmt.query(params![id])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(DictItem {
+                id: row.get(0)?,
+                name: row.get(1)?,
+            }));
+        }
+        Ok(None)
+    }
+
+    pub fn find_dict_exact(&self, kind: DictKind, name: &str) -> Result<Vec<DictItem>, AppError> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT CAST(id AS TEXT), name FROM {} WHERE LOWER(name) = LOWER(?) ORDER BY id ASC",
+            kind.table()
+        ))?;
+        let rows = stmt.query_map(params![name.trim()], |row| {
+            Ok(DictItem {
+                id: row.get(0)?,
+                name: row.get(1)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row?);
+        }
+        Ok(out)
+    }
+
+    /// Upserts catalog rows. `apps_fts` rows use the appid as rowid so updates
+    /// replace the old entry without scanning the index.
+    pub fn upsert_catalog_apps(&self, apps: &[CatalogApp]) -> Result<usize, AppError> {
+        let tx = self.conn.unchecked_transaction()?;
+        insert_catalog_apps(&tx, apps)?;
+        tx.commit()?;
+        Ok(apps.len())
+    }
+
+    /// Replaces the whole catalog in one transaction, so apps Steam no longer lists
+    /// stop resolving. Every catalog sync point is reset as well: the other type
+    /// selections' apps are gone and must be downloaded again.
+    pub fn replace_catalog_apps(&self, apps: &[CatalogApp]) -> Result<usize, AppError> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute_batch(
+            "
+            DELETE FROM apps;
+            DELETE FROM apps_fts;
+            DELETE FROM sync_state WHERE name LIKE 'catalog_synced_at:%';
+            ",
+        )?;
+        insert_catalog_apps(&tx, apps)?;
+        tx.commit()?;
+        Ok(apps.len())
+    }
+
+    pub fn count_dict(&self, kind: DictKind) -> Result<usize, AppError> {
+        let count = self.conn.query_row(
+            &format!("SELECT COUNT(*) FROM {}", kind.table()),
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(count)
+    }
+
+    pub fn get_sync_state(&self, name: &str) -> Result<Option<i64>, AppError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT value FROM sync_state WHERE name = ?")?;
+        let mut rows = stmt.query(params![name])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+
+    pub fn set_sync_state(&self, name: &str, value: i64) -> Result<(), AppError> {
+        self.conn.execute(
+            "INSERT INTO sync_state(name, value) VALUES(?, ?) ON CONFLICT(name) DO UPDATE SET value = excluded.value",
+            params![name, value],
+        )?;
+        Ok(())
+    }
+
     pub fn get_cached_app(
         &self,
         appid: i64,
+        locale: &StoreLocale,
         min_fetched_at: i64,
     ) -> Result<Option<String>, AppError> {
-        let mut stmt = self
-            .conn
-            .prepare("SELECT payload_json FROM app_cache WHERE appid = ? AND fetched_at >= ?")?;
-        let mut rows = stmt.query(params![appid, min_fetched_at])?;
+        let mut stmt = self.conn.prepare(
+            "SELECT payload_json FROM app_cache WHERE appid = ? AND cc = ? AND lang = ? AND fetched_at >= ?",
+        )?;
+        let mut rows = stmt.query(params![
+            appid,
+            locale.cache_cc(),
+            locale.lang,
+            min_fetched_at
+        ])?;
         if let Some(row) = rows.next()? {
             return Ok(Some(row.get(0)?));
         }
@@ -250,12 +475,360 @@ impl LocalStore {
     pub fn put_cached_app(
         &self,
         appid: i64,
+        locale: &StoreLocale,
+        payload_json: &str,
+        fetched_at: i64,
+    ) -> Result<(), AppError> {
+        self.conn.execute(
+            "INSERT INTO app_cache(appid, cc, lang, payload_json, fetched_at) VALUES(?, ?, ?, ?, ?) ON CONFLICT(appid, cc, lang) DO UPDATE SET payload_json = excluded.payload_json, fetched_at = excluded.fetched_at",
+            params![appid, locale.cache_cc(), locale.lang, payload_json, fetched_at],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_cached_package(
+        &self,
+        subid: i64,
+        locale: &StoreLocale,
+        min_fetched_at: i64,
+    ) -> Result<Option<String>, AppError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT payload_json FROM package_cache WHERE subid = ? AND cc = ? AND lang = ? AND fetched_at >= ?",
+        )?;
+        let mut rows = stmt.query(params![
+            subid,
+            locale.cache_cc(),
+            locale.lang,
+            min_fetched_at
+        ])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+
+    pub fn put_cached_package(
+        &self,
+        subid: i64,
+        locale: &StoreLocale,
+        payload_json: &str,
+        fetched_at: i64,
+    ) -> Result<(), AppError> {
+        self.conn.execute(
+            "INSERT INTO package_cache(subid, cc, lang, payload_json, fetched_at) VALUES(?, ?, ?, ?, ?) ON CONFLICT(subid, cc, lang) DO UPDATE SET payload_json = excluded.payload_json, fetched_at = excluded.fetched_at",
+            params![subid, locale.cache_cc(), locale.lang, payload_json, fetched_at],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_cached_search(
+        &self,
+        query_key: &str,
+        min_fetched_at: i64,
+    ) -> Result<Option<String>, AppError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT payload_json FROM search_cache WHERE query_key = ? AND fetched_at >= ?",
+        )?;
+        let mut rows = stmt.query(params![query_key, min_fetched_at])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+
+    pub fn put_cached_search(
+        &self,
+        query_key: &str,
         payload_json: &str,
         fetched_at: i64,
     ) -> Result<(), AppError> {
         self.conn.execute(
-            "INSERT INTO app_cache(appid, payload_json, fetched_at) VALUES(?, ?, ?) ON CONFLICT(appid) DO UPDATE SET payload_json = excluded.payload_json, fetched_at = excluded.fetched_at",
-            params![appid, payload_json, fetched_at],
+            "INSERT INTO search_cache(query_key, payload_json, fetched_at) VALUES(?, ?, ?) ON CONFLICT(query_key) DO UPDATE SET payload_json = excluded.payload_json, fetched_at = excluded.fetched_at",
+            params![query_key, payload_json, fetched_at],
+        )?;
+        Ok(())
+    }
+
+    /// True when news for this appid and fetch key was pulled at or after `min_fetched_at`.
+    pub fn news_fetched_since(
+        &self,
+        appid: i64,
+        query_key: &str,
+        min_fetched_at: i64,
+    ) -> Result<bool, AppError> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM app_news_fetch WHERE appid = ? AND query_key = ? AND fetched_at >= ?",
+            params![appid, query_key, min_fetched_at],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    pub fn put_news(
+        &self,
+        appid: i64,
+        query_key: &str,
+        items: &[NewsItem],
+        fetched_at: i64,
+    ) -> Result<(), AppError> {
+        let tx = self.conn.unchecked_transaction()?;
+        for item in items {
+            tx.execute(
+                "INSERT INTO app_news(appid, gid, title, url, author, date, feedlabel, feedname, contents) VALUES(?, ?, ?, ?, ?, ?, ?, ?, ?) ON CONFLICT(appid, gid) DO UPDATE SET title = excluded.title, url = excluded.url, author = excluded.author, date = excluded.date, feedlabel = excluded.feedlabel, feedname = excluded.feedname, contents = excluded.contents",
+                params![
+                    appid,
+                    item.gid,
+                    item.title,
+                    item.url,
+                    item.author,
+                    item.date,
+                    item.feedlabel,
+                    item.feedname,
+                    item.contents
+                ],
+            )?;
+        }
+        tx.execute(
+            "INSERT INTO app_news_fetch(appid, query_key, fetched_at) VALUES(?, ?, ?) ON CONFLICT(appid, query_key) DO UPDATE SET fetched_at = excluded.fetched_at",
+            params![appid, query_key, fetched_at],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Newest first; `feeds` empty keeps every feed.
+    pub fn list_news(
+        &self,
+        appid: i64,
+        since: Option<i64>,
+        feeds: &[String],
+        limit: usize,
+    ) -> Result<Vec<NewsItem>, AppError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT gid, title, url, author, date, feedlabel, feedname, contents FROM app_news WHERE appid = ? AND date >= ? ORDER BY date DESC, gid DESC",
+        )?;
+        let rows = stmt.query_map(params![appid, since.unwrap_or(i64::MIN)], |row| {
+            Ok(NewsItem {
+                gid: row.get(0)?,
+                title: row.get(1)?,
+                url: row.get(2)?,
+                author: row.get(3)?,
+                date: row.get(4)?,
+                feedlabel: row.get(5)?,
+                feedname: row.get(6)?,
+                contents: row.get(7)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            let item = row?;
+            let feed_ok = feeds.is_empty()
+                || item
+                    .feedname
+                    .as_deref()
+                    .is_some_and(|name| feeds.iter().any(|f| f.eq_ignore_ascii_case(name)));
+            if feed_ok {
+                out.push(item);
+            }
+            if out.len() >= limit {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Cached store-page tags in page order; None when never fetched or older than
+    /// `min_fetched_at`. An app without tags is cached as an empty list.
+    pub fn get_cached_app_tags(
+        &self,
+        appid: i64,
+        locale: &StoreLocale,
+        min_fetched_at: i64,
+    ) -> Result<Option<Vec<AppTag>>, AppError> {
+        let fetched: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM app_tags_fetch WHERE appid = ? AND lang = ? AND fetched_at >= ?",
+            params![appid, locale.lang, min_fetched_at],
+            |row| row.get(0),
+        )?;
+        if fetched == 0 {
+            return Ok(None);
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT tagid, name, votes FROM app_tags WHERE appid = ? AND lang = ? ORDER BY position ASC",
+        )?;
+        let rows = stmt.query_map(params![appid, locale.lang], |row| {
+            Ok(AppTag {
+                tagid: row.get(0)?,
+                name: row.get(1)?,
+                votes: row.get(2)?,
+                unknown: false,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row?);
+        }
+        Ok(Some(out))
+    }
+
+    pub fn put_app_tags(
+        &self,
+        appid: i64,
+        locale: &StoreLocale,
+        tags: &[AppTag],
+        fetched_at: i64,
+    ) -> Result<(), AppError> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "DELETE FROM app_tags WHERE appid = ? AND lang = ?",
+            params![appid, locale.lang],
+        )?;
+        for (position, tag) in tags.iter().enumerate() {
+            tx.execute(
+                "INSERT INTO app_tags(appid, lang, tagid, name, votes, position, fetched_at) VALUES(?, ?, ?, ?, ?, ?, ?)",
+                params![
+                    appid,
+                    locale.lang,
+                    tag.tagid,
+                    tag.name,
+                    tag.votes,
+                    position as i64,
+                    fetched_at
+                ],
+            )?;
+        }
+        tx.execute(
+            "INSERT INTO app_tags_fetch(appid, lang, fetched_at) VALUES(?, ?, ?) ON CONFLICT(appid, lang) DO UPDATE SET fetched_at = excluded.fetched_at",
+            params![appid, locale.lang, fetched_at],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Cached store-page franchise; `Some(None)` when the page names none, None when
+    /// never fetched or older than `min_fetched_at`.
+    pub fn get_cached_app_franchise(
+        &self,
+        appid: i64,
+        locale: &StoreLocale,
+        min_fetched_at: i64,
+    ) -> Result<Option<Option<String>>, AppError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT name FROM app_franchise WHERE appid = ? AND lang = ? AND fetched_at >= ?",
+        )?;
+        let mut rows = stmt.query(params![appid, locale.lang, min_fetched_at])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+
+    pub fn put_app_franchise(
+        &self,
+        appid: i64,
+        locale: &StoreLocale,
+        name: Option<&str>,
+        fetched_at: i64,
+    ) -> Result<(), AppError> {
+        self.conn.execute(
+            "INSERT INTO app_franchise(appid, lang, name, fetched_at) VALUES(?, ?, ?, ?) ON CONFLICT(appid, lang) DO UPDATE SET name = excluded.name, fetched_at = excluded.fetched_at",
+            params![appid, locale.lang, name, fetched_at],
+        )?;
+        Ok(())
+    }
+
+    /// Cached store-page language table as JSON; None when missing or stale.
+    pub fn get_cached_app_languages(
+        &self,
+        appid: i64,
+        locale: &StoreLocale,
+        min_fetched_at: i64,
+    ) -> Result<Option<String>, AppError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT payload_json FROM app_languages WHERE appid = ? AND lang = ? AND fetched_at >= ?",
+        )?;
+        let mut rows = stmt.query(params![appid, locale.lang, min_fetched_at])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+
+    pub fn put_app_languages(
+        &self,
+        appid: i64,
+        locale: &StoreLocale,
+        payload_json: &str,
+        fetched_at: i64,
+    ) -> Result<(), AppError> {
+        self.conn.execute(
+            "INSERT INTO app_languages(appid, lang, payload_json, fetched_at) VALUES(?, ?, ?, ?) ON CONFLICT(appid, lang) DO UPDATE SET payload_json = excluded.payload_json, fetched_at = excluded.fetched_at",
+            params![appid, locale.lang, payload_json, fetched_at],
+        )?;
+        Ok(())
+    }
+
+    pub fn put_player_sample(
+        &self,
+        appid: i64,
+        sampled_at: i64,
+        player_count: i64,
+    ) -> Result<(), AppError> {
+        self.conn.execute(
+            "INSERT INTO player_samples(appid, sampled_at, player_count) VALUES(?, ?, ?) ON CONFLICT(appid, sampled_at) DO UPDATE SET player_count = excluded.player_count",
+            params![appid, sampled_at, player_count],
+        )?;
+        Ok(())
+    }
+
+    pub fn player_history(&self, appid: i64, since: i64) -> Result<PlayerHistory, AppError> {
+        let history = self.conn.query_row(
+            "SELECT COUNT(*), MIN(player_count), MAX(player_count), AVG(player_count), MIN(sampled_at), MAX(sampled_at) FROM player_samples WHERE appid = ? AND sampled_at >= ?",
+            params![appid, since],
+            |row| {
+                Ok(PlayerHistory {
+                    appid,
+                    samples: row.get(0)?,
+                    min: row.get(1)?,
+                    max: row.get(2)?,
+                    avg: row.get(3)?,
+                    first_sampled_at: row.get(4)?,
+                    last_sampled_at: row.get(5)?,
+                })
+            },
+        )?;
+        Ok(history)
+    }
+
+    pub fn get_cached_review_summary(
+        &self,
+        appid: i64,
+        query_key: &str,
+        min_fetched_at: i64,
+    ) -> Result<Option<String>, AppError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT payload_json FROM review_summary_cache WHERE appid = ? AND query_key = ? AND fetched_at >= ?",
+        )?;
+        let mut rows = stmt.query(params![appid, query_key, min_fetched_at])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(row.get(0)?));
+        }
+        Ok(None)
+    }
+
+    pub fn put_cached_review_summary(
+        &self,
+        appid: i64,
+        query_key: &str,
+        payload_json: &str,
+        fetched_at: i64,
+    ) -> Result<(), AppError> {
+        self.conn.execute(
+            "INSERT INTO review_summary_cache(appid, query_key, payload_json, fetched_at) VALUES(?, ?, ?, ?) ON CONFLICT(appid, query_key) DO UPDATE SET payload_json = excluded.payload_json, fetched_at = excluded.fetched_at",
+            params![appid, query_key, payload_json, fetched_at],
         )?;
         Ok(())
     }
@@ -275,3 +848,127 @@ fn to_fts_query(input: &str) -> String {
     }
     terms.join(" AND ")
 }
+
+fn insert_catalog_apps(conn: &Connection, apps: &[CatalogApp]) -> Result<(), AppError> {
+    let mut upsert = conn.prepare(
+        "INSERT INTO apps(id, name, last_modified) VALUES(?, ?, ?) ON CONFLICT(id) DO UPDATE SET name = excluded.name, last_modified = excluded.last_modified",
+    )?;
+    let mut fts_delete = conn.prepare("DELETE FROM apps_fts WHERE rowid = ?")?;
+    let mut fts_insert = conn.prepare("INSERT INTO apps_fts(rowid, id, name) VALUES(?, ?, ?)")?;
+    for app in apps {
+        upsert.execute(params![app.appid, app.name, app.last_modified])?;
+        fts_delete.execute(params![app.appid])?;
+        fts_insert.execute(params![app.appid, app.appid.to_string(), app.name])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apps_without_tags_are_cached_as_empty() {
+        let store = LocalStore::open_in_memory().unwrap();
+        let locale = StoreLocale::default();
+
+        assert!(store.get_cached_app_tags(10, &locale, 0).unwrap().is_none());
+        store.put_app_tags(10, &locale, &[], 1_000).unwrap();
+        assert_eq!(
+            store
+                .get_cached_app_tags(10, &locale, 1_000)
+                .unwrap()
+                .map(|t| t.len()),
+            Some(0)
+        );
+        assert!(
+            store
+                .get_cached_app_tags(10, &locale, 1_001)
+                .unwrap()
+                .is_none()
+        );
+
+        let tag = AppTag {
+            tagid: 19,
+            name: "Action".to_string(),
+            votes: 5,
+            unknown: false,
+        };
+        store.put_app_tags(10, &locale, &[tag], 2_000).unwrap();
+        let cached = store
+            .get_cached_app_tags(10, &locale, 2_000)
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].name, "Action");
+    }
+
+    #[test]
+    fn apps_without_a_franchise_are_cached_as_none() {
+        let store = LocalStore::open_in_memory().unwrap();
+        let locale = StoreLocale::default();
+
+        assert_eq!(
+            store.get_cached_app_franchise(10, &locale, 0).unwrap(),
+            None
+        );
+        store.put_app_franchise(10, &locale, None, 1_000).unwrap();
+        assert_eq!(
+            store.get_cached_app_franchise(10, &locale, 1_000).unwrap(),
+            Some(None)
+        );
+        store
+            .put_app_franchise(10, &locale, Some("Half-Life"), 2_000)
+            .unwrap();
+        assert_eq!(
+            store.get_cached_app_franchise(10, &locale, 2_000).unwrap(),
+            Some(Some("Half-Life".to_string()))
+        );
+        assert_eq!(
+            store.get_cached_app_franchise(10, &locale, 2_001).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn replacing_the_catalog_drops_unlisted_apps() {
+        let store = LocalStore::open_in_memory().unwrap();
+        let app = |appid: i64, name: &str| CatalogApp {
+            appid,
+            name: name.to_string(),
+            last_modified: None,
+        };
+        store
+            .upsert_catalog_apps(&[app(10, "Counter-Strike"), app(20, "Team Fortress Classic")])
+            .unwrap();
+        store
+            .set_sync_state("catalog_synced_at:dlc=1:software=0", 5)
+            .unwrap();
+
+        store
+            .replace_catalog_apps(&[app(10, "Counter-Strike")])
+            .unwrap();
+
+        assert_eq!(store.count_dict(DictKind::Apps).unwrap(), 1);
+        assert!(
+            store
+                .find_dict(DictKind::Apps, "fortress", 10, 0)
+                .unwrap()
+                .0
+                .is_empty()
+        );
+        assert_eq!(
+            store
+                .find_dict_exact(DictKind::Apps, "counter-strike")
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            store
+                .get_sync_state("catalog_synced_at:dlc=1:software=0")
+                .unwrap()
+                .is_none()
+        );
+    }
+}